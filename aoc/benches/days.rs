@@ -0,0 +1,80 @@
+#[cfg(feature = "parallel")]
+use common::Generator;
+use common::{input::get_input_path, Solution};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::{fs, hint::black_box};
+
+// Time parsing and each part on their own, for the example and, when
+// there's one, the day's input.txt
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let mut inputs = vec![("example", S::EXAMPLE.to_string())];
+    if let Ok(input) = fs::read_to_string(get_input_path(S::DAY)) {
+        inputs.push(("input", input));
+    }
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    for (name, contents) in &inputs {
+        let parsed = match S::parse(contents) {
+            Ok(value) => value,
+            Err(err) => {
+                eprintln!("Skipping day {:02} {}, invalid at {}", S::DAY, name, err);
+                continue;
+            }
+        };
+
+        group.bench_with_input(BenchmarkId::new("parse", name), contents, |b, input| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part_one", name), &parsed, |b, input| {
+            b.iter(|| S::part_one(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part_two", name), &parsed, |b, input| {
+            b.iter(|| S::part_two(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+// Day 12's walk back from every lowest square, sequential and split between
+// threads, on a generated map. Only the parallel feature builds both
+#[cfg(feature = "parallel")]
+fn bench_day12_lowest(c: &mut Criterion) {
+    use day12::{dijkstra_pathfind, get_min_steps_from_lowest, Day12};
+
+    let input = Day12::generate(1, Day12::INPUT_SIZE).0;
+    let (graph, _, target) = Day12::parse(&input).unwrap();
+    let previous = dijkstra_pathfind(&graph, &target, true);
+
+    let mut group = c.benchmark_group("day12_lowest");
+    group.bench_function("pathfind", |b| {
+        b.iter(|| dijkstra_pathfind(black_box(&graph), &target, true))
+    });
+    group.bench_function("sequential", |b| {
+        b.iter(|| get_min_steps_from_lowest(black_box(&previous), &target))
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| day12::get_min_steps_from_lowest_parallel(black_box(&previous), &target))
+    });
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c);
+    bench_day::<day02::Day02>(c);
+    bench_day::<day03::Day03>(c);
+    bench_day::<day04::Day04>(c);
+    bench_day::<day05::Day05>(c);
+    bench_day::<day06::Day06>(c);
+    bench_day::<day07::Day07>(c);
+    bench_day::<day08::Day08>(c);
+    bench_day::<day09::Day09>(c);
+    bench_day::<day10::Day10>(c);
+    bench_day::<day11::Day11>(c);
+    bench_day::<day12::Day12>(c);
+    bench_day::<day13::Day13>(c);
+    #[cfg(feature = "parallel")]
+    bench_day12_lowest(c);
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,90 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+// The system allocator, counting every allocation and how much of the heap
+// is in use so each step of a run can report what it cost
+pub struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        return new_ptr;
+    }
+}
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+// What a step of a run cost. The peak is how far the heap grew above what
+// was already in use when the step started
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Usage {
+    pub elapsed: Duration,
+    pub allocations: u64,
+    pub peak_bytes: usize,
+}
+
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let start_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start_bytes = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start_bytes, Ordering::Relaxed);
+
+    let start = Instant::now();
+    let value = f();
+    let elapsed = start.elapsed();
+
+    let usage = Usage {
+        elapsed,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - start_allocations,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(start_bytes),
+    };
+    return (value, usage);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations() {
+        let (value, usage) = measure(|| vec![0u8; 4096].len());
+
+        assert_eq!(value, 4096);
+        assert!(usage.allocations >= 1);
+    }
+}
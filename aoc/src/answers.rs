@@ -0,0 +1,82 @@
+use common::input::get_day_dir;
+use serde::Deserialize;
+use std::{fs, io, path::PathBuf};
+use toml::Value;
+
+// Known answers for a day, kept in answers.toml next to its crate.
+// Answers can be numbers or strings, and a missing one isn't checked
+#[derive(Debug, Default, Deserialize)]
+pub struct KnownAnswers {
+    #[serde(default)]
+    pub example: PartAnswers,
+    #[serde(default)]
+    pub input: PartAnswers,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PartAnswers {
+    part_one: Option<Value>,
+    part_two: Option<Value>,
+}
+
+impl PartAnswers {
+    pub fn get(&self, part: u8) -> Option<String> {
+        let value = match part {
+            1 => self.part_one.as_ref(),
+            _ => self.part_two.as_ref(),
+        }?;
+        return match value {
+            // Multi-line answers, like a drawn image, end in a newline
+            Value::String(text) => Some(text.trim_end().to_string()),
+            value => Some(value.to_string()),
+        };
+    }
+}
+
+pub fn get_answers_path(day: u8) -> PathBuf {
+    let mut path = get_day_dir(day);
+    path.push("answers.toml");
+    return path;
+}
+
+pub fn parse_answers(contents: &str) -> Result<KnownAnswers, String> {
+    return toml::from_str(contents).map_err(|err| err.message().to_string());
+}
+
+// A day without an answers.toml has every answer missing
+pub fn load_answers(day: u8) -> Result<KnownAnswers, String> {
+    let path = get_answers_path(day);
+    let contents = match fs::read_to_string(&path) {
+        Ok(value) => value,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(KnownAnswers::default()),
+        Err(err) => return Err(format!("Could not read {}: {}", path.display(), err)),
+    };
+    return parse_answers(&contents).map_err(|err| format!("Invalid {}: {}", path.display(), err));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_and_text() {
+        let answers = parse_answers(
+            "[example]\npart_one = 24000\npart_two = \"CMZ\"\n\n[input]\npart_two = \"\"\"\n#..\n.#.\n\"\"\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.example.get(1).as_deref(), Some("24000"));
+        assert_eq!(answers.example.get(2).as_deref(), Some("CMZ"));
+        assert_eq!(answers.input.get(1), None);
+        assert_eq!(answers.input.get(2).as_deref(), Some("#..\n.#."));
+    }
+
+    #[test]
+    fn every_day_has_answers() {
+        for day in 1..=13 {
+            let answers = load_answers(day).unwrap();
+            assert!(answers.example.get(1).is_some(), "day {}", day);
+            assert!(answers.example.get(2).is_some(), "day {}", day);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::alloc::{self, Usage};
+use common::{
+    Answer, Generated, Generator, ParseError, Solution, SolveError, StreamError, Streaming,
+};
+use std::io::BufRead;
+use tracing::info_span;
+
+pub struct PartResult {
+    pub part: u8,
+    // A part can fail on its own, even when its input parsed
+    pub answer: Result<Answer, SolveError>,
+    // None when streamed, as both parts are solved at once
+    pub usage: Option<Usage>,
+}
+
+pub struct DayResult {
+    // Parsing, or reading and solving everything when streamed
+    pub parse: Usage,
+    pub parts: Vec<PartResult>,
+    pub streamed: bool,
+}
+
+// Parse the input once, then solve each of the requested parts
+type Runner = fn(&str, &[u8]) -> Result<DayResult, ParseError>;
+
+// Solve both parts straight from a reader, keeping the requested ones
+type StreamRunner = fn(&mut dyn BufRead, &[u8]) -> Result<DayResult, StreamError>;
+
+pub struct Day {
+    pub number: u8,
+    pub example: &'static str,
+    pub run: Runner,
+    // Random input from a seed, and about how big a real one is
+    pub generate: fn(u64, usize) -> Generated,
+    pub input_size: usize,
+    // Only for days whose input can be read a line at a time
+    pub stream: Option<StreamRunner>,
+}
+
+impl Day {
+    const fn of<S: Solution + Generator>() -> Self {
+        return Day {
+            number: S::DAY,
+            example: S::EXAMPLE,
+            run: run::<S>,
+            generate: S::generate,
+            input_size: S::INPUT_SIZE,
+            stream: None,
+        };
+    }
+
+    const fn streaming<S: Solution + Generator + Streaming>() -> Self {
+        return Day {
+            stream: Some(stream::<S>),
+            ..Self::of::<S>()
+        };
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<DayResult, ParseError> {
+    let (parsed, parse) = info_span!("parse").in_scope(|| alloc::measure(|| S::parse(input)));
+    let parsed = parsed?;
+
+    let mut results = Vec::new();
+    for part in parts {
+        let (answer, usage) = info_span!("part", number = part).in_scope(|| {
+            alloc::measure(|| match part {
+                1 => S::part_one(&parsed),
+                _ => S::part_two(&parsed),
+            })
+        });
+        results.push(PartResult {
+            part: *part,
+            answer,
+            usage: Some(usage),
+        });
+    }
+    return Ok(DayResult {
+        parse,
+        parts: results,
+        streamed: false,
+    });
+}
+
+fn stream<S: Streaming>(reader: &mut dyn BufRead, parts: &[u8]) -> Result<DayResult, StreamError> {
+    let (answers, usage) =
+        info_span!("stream").in_scope(|| alloc::measure(|| S::solve_stream(reader)));
+    let (one, two) = answers?;
+
+    let results = parts
+        .iter()
+        .map(|part| PartResult {
+            part: *part,
+            answer: Ok(if *part == 1 { one.clone() } else { two.clone() }),
+            usage: None,
+        })
+        .collect();
+    return Ok(DayResult {
+        parse: usage,
+        parts: results,
+        streamed: true,
+    });
+}
+
+pub const DAYS: [Day; 13] = [
+    Day::streaming::<day01::Day01>(),
+    Day::streaming::<day02::Day02>(),
+    Day::streaming::<day03::Day03>(),
+    Day::streaming::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::streaming::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::streaming::<day09::Day09>(),
+    Day::streaming::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    return DAYS.iter().find(|day| day.number == number);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{self, Write},
+        sync::{Arc, Mutex},
+    };
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            return Ok(buf.len());
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            return Ok(());
+        }
+    }
+
+    #[test]
+    fn explain_directory_sizes() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_env_filter("debug")
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .without_time()
+            .finish();
+
+        let day = get_day(7).unwrap();
+        tracing::subscriber::with_default(subscriber, || (day.run)(day.example, &[1]).unwrap());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.contains("part{number=1}: day07: directory path=/a size=94853"),
+            "{}",
+            output
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use common::{
+    input::{get_input_path, InputSource},
+    StreamError,
+};
+use days::DayResult;
+use output::{Elapsed, Format};
+use std::{env, process};
+use tracing::info_span;
+
+mod alloc;
+mod answers;
+mod days;
+mod output;
+mod verify;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+const USAGE: &str =
+    "Usage: aoc run <day|all> [<input path> | - | --example] [--part <1|2>] [--format <text|json>] [--stats] [--stream] [--parallel] [--explain]
+       aoc verify [<day|all>]
+       aoc generate <day> [--seed <n>] [--size <n>]
+       aoc watch <day>";
+
+enum Command {
+    Run(RunArgs),
+    // Check answers against each day's answers.toml
+    Verify(Vec<&'static days::Day>),
+    // Print a random input, the size of a real one unless given, and its
+    // answers when they're known
+    Generate {
+        day: &'static days::Day,
+        seed: u64,
+        size: Option<usize>,
+    },
+    // Re-run a day's tests and solver whenever its files change
+    Watch(&'static days::Day),
+}
+
+struct RunArgs {
+    days: Vec<&'static days::Day>,
+    parts: Vec<u8>,
+    // Each day's input.txt is used when there's no source
+    source: Option<InputSource>,
+    format: Format,
+    // Print how long each step took and how much it allocated
+    stats: bool,
+    // Read inputs bit by bit, for the days that can be solved that way
+    stream: bool,
+    // Solve every day at once, when built with the parallel feature
+    parallel: bool,
+    // Log how each day gets to its answers, filtered by RUST_LOG
+    explain: bool,
+}
+
+fn parse_days(arg: &str) -> Result<Vec<&'static days::Day>, String> {
+    return match arg {
+        "all" => Ok(days::DAYS.iter().collect()),
+        day => match day.parse::<u8>().ok().and_then(days::get_day) {
+            Some(day) => Ok(vec![day]),
+            None => Err(format!("Unknown day '{}'", day)),
+        },
+    };
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    match args.next().map(|v| v.as_str()) {
+        Some("run") => return parse_run_args(args).map(Command::Run),
+        Some("verify") => {}
+        Some("generate") => return parse_generate_args(args),
+        Some("watch") => return parse_watch_args(args),
+        Some(command) => return Err(format!("Unknown command '{}'", command)),
+        None => return Err(String::from("Expected a command")),
+    }
+
+    let days = match args.next() {
+        Some(arg) => parse_days(arg)?,
+        None => days::DAYS.iter().collect(),
+    };
+    if let Some(arg) = args.next() {
+        return Err(format!("Unknown argument '{}'", arg));
+    }
+    return Ok(Command::Verify(days));
+}
+
+fn parse_generate_args(mut args: std::slice::Iter<String>) -> Result<Command, String> {
+    let day = match args.next() {
+        Some(arg) => match parse_days(arg)?.as_slice() {
+            [day] => *day,
+            _ => return Err(String::from("Expected a single day to generate input for")),
+        },
+        None => return Err(String::from("Expected a day")),
+    };
+
+    let mut seed = 0;
+    let mut size = None;
+    while let Some(arg) = args.next() {
+        let value = args.next().map(|v| v.as_str());
+        match arg.as_str() {
+            "--seed" => match value.and_then(|v| v.parse().ok()) {
+                Some(value) => seed = value,
+                None => return Err(String::from("Expected seed to be a number")),
+            },
+            "--size" => match value.and_then(|v| v.parse().ok()) {
+                Some(value) => size = Some(value),
+                None => return Err(String::from("Expected size to be a number")),
+            },
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    return Ok(Command::Generate { day, seed, size });
+}
+
+fn parse_watch_args(mut args: std::slice::Iter<String>) -> Result<Command, String> {
+    let day = match args.next() {
+        Some(arg) => match parse_days(arg)?.as_slice() {
+            [day] => *day,
+            _ => return Err(String::from("Expected a single day to watch")),
+        },
+        None => return Err(String::from("Expected a day")),
+    };
+    if let Some(arg) = args.next() {
+        return Err(format!("Unknown argument '{}'", arg));
+    }
+    return Ok(Command::Watch(day));
+}
+
+fn parse_run_args(mut args: std::slice::Iter<String>) -> Result<RunArgs, String> {
+    let days = match args.next() {
+        Some(arg) => parse_days(arg)?,
+        None => return Err(String::from("Expected a day")),
+    };
+
+    let mut parts = vec![1, 2];
+    let mut source = None;
+    let mut format = Format::Text;
+    let mut stats = false;
+    let mut stream = false;
+    let mut parallel = false;
+    let mut explain = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--example" | "-" => source = Some(InputSource::from_arg(arg)),
+            path if !path.starts_with('-') => source = Some(InputSource::from_arg(path)),
+            "--part" | "-p" => {
+                let part = args.next().map(|v| v.as_str());
+                parts = match part {
+                    Some("1") => vec![1],
+                    Some("2") => vec![2],
+                    _ => return Err(String::from("Expected part to be 1 or 2")),
+                };
+            }
+            "--format" | "-f" => {
+                format = match args.next().and_then(|v| Format::from_arg(v)) {
+                    Some(value) => value,
+                    None => return Err(String::from("Expected format to be text or json")),
+                };
+            }
+            "--stats" => stats = true,
+            "--stream" => stream = true,
+            "--explain" => explain = true,
+            "--parallel" if cfg!(feature = "parallel") => parallel = true,
+            "--parallel" => {
+                return Err(String::from(
+                    "Expected aoc to be built with --features parallel",
+                ))
+            }
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    if days.len() > 1 && matches!(source, Some(InputSource::File(_) | InputSource::Stdin)) {
+        return Err(String::from("Expected a single day to read its input from"));
+    }
+    // Every day would be counted by the same allocator at once
+    if parallel && stats {
+        return Err(String::from("Expected --stats without --parallel"));
+    }
+
+    return Ok(RunArgs {
+        days,
+        parts,
+        source,
+        format,
+        stats,
+        stream,
+        parallel,
+        explain,
+    });
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    // Explaining shows everything the days log at debug level, unless
+    // RUST_LOG picks something else
+    match &command {
+        Command::Run(run_args) if run_args.explain => common::log::init_with("debug"),
+        _ => common::log::init(),
+    }
+
+    match command {
+        Command::Run(run_args) => run(run_args),
+        // The answers go to stderr, so the input can be piped on its own
+        Command::Generate { day, seed, size } => {
+            let (input, (one, two)) = (day.generate)(seed, size.unwrap_or(day.input_size));
+            print!("{}", input);
+            eprintln!("Part 1: {}\nPart 2: {}", one, two);
+        }
+        Command::Watch(day) => {
+            if let Err(err) = watch::watch(day) {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+        Command::Verify(days) => match verify::verify_days(&days) {
+            Ok(true) => {}
+            Ok(false) => process::exit(1),
+            Err(err) => {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        },
+    }
+}
+
+// Solve a day, streaming its input if asked to and it can be. Errors are
+// ready to be shown
+fn solve(day: &days::Day, run_args: &RunArgs) -> Result<DayResult, String> {
+    let source = match &run_args.source {
+        Some(source) => source.clone(),
+        None => InputSource::File(get_input_path(day.number)),
+    };
+    let _day = info_span!("day", number = day.number).entered();
+    if let (true, Some(stream)) = (run_args.stream, day.stream) {
+        let mut reader = source.open(day.example).map_err(|err| err.to_string())?;
+        return match stream(&mut reader, &run_args.parts) {
+            Ok(value) => Ok(value),
+            Err(StreamError::Parse(err)) => Err(format!("Invalid {} at {}", source, err)),
+            Err(err) => Err(err.to_string()),
+        };
+    }
+
+    let input_contents = source.read(day.example).map_err(|err| err.to_string())?;
+    return (day.run)(&input_contents, &run_args.parts)
+        .map_err(|err| format!("Invalid {} at {}", source, err));
+}
+
+// Solve every day at once when asked to, keeping them in order. Otherwise
+// days are solved one at a time, as they're shown
+fn solve_ahead(run_args: &RunArgs) -> Vec<Result<DayResult, String>> {
+    if !run_args.parallel {
+        return Vec::new();
+    }
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        return run_args
+            .days
+            .par_iter()
+            .map(|day| solve(day, run_args))
+            .collect();
+    }
+    #[cfg(not(feature = "parallel"))]
+    return Vec::new();
+}
+
+fn run(run_args: RunArgs) {
+    let mut failed = false;
+    let mut steps = Vec::new();
+
+    let mut solved = solve_ahead(&run_args).into_iter();
+    for day in &run_args.days {
+        let result = solved.next().unwrap_or_else(|| solve(day, &run_args));
+
+        if run_args.format == Format::Text {
+            println!("Day {:02}", day.number);
+        }
+        let results = match result {
+            Ok(value) => value,
+            Err(err) => {
+                eprintln!("{}", err);
+                failed = true;
+                continue;
+            }
+        };
+        let step = if results.streamed { "stream" } else { "parse" };
+        steps.push((day.number, String::from(step), results.parse));
+        for result in &results.parts {
+            let elapsed = match result.usage {
+                Some(usage) => Elapsed::Part(usage.elapsed),
+                None => Elapsed::Stream(results.parse.elapsed),
+            };
+            match &result.answer {
+                Ok(answer) => println!(
+                    "{}",
+                    output::format_result(
+                        run_args.format,
+                        day.number,
+                        result.part,
+                        answer,
+                        elapsed
+                    )
+                ),
+                Err(err) => {
+                    eprintln!("Could not solve part {}: {}", result.part, err);
+                    failed = true;
+                }
+            }
+            if let Some(usage) = result.usage {
+                steps.push((day.number, format!("part {}", result.part), usage));
+            }
+        }
+    }
+
+    // Keep JSON output parseable by sending the table elsewhere
+    if run_args.stats && run_args.format == Format::Json {
+        eprintln!("{}", output::format_stats(&steps));
+    } else if run_args.stats {
+        println!("\n{}", output::format_stats(&steps));
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::alloc::Usage;
+use common::Answer;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    // One JSON record per line, for piping into other tools
+    Json,
+}
+
+// How long getting to an answer took: solving its part on its own, or
+// streaming the input, which solves both parts at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Elapsed {
+    Part(Duration),
+    Stream(Duration),
+}
+
+// Streamed parts both give the time of the whole pass as elapsed_ns, and
+// are marked as streamed so it isn't counted twice. Records of parts solved
+// on their own leave the mark out
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    elapsed_ns: u128,
+    #[serde(skip_serializing_if = "is_false")]
+    stream: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    return !value;
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        return match arg {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        };
+    }
+}
+
+pub fn format_result(
+    format: Format,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+    elapsed: Elapsed,
+) -> String {
+    return match (format, answer) {
+        (Format::Text, Answer::Grid(_)) => format!("  Part {}:\n{}", part, answer),
+        (Format::Text, answer) => format!("  Part {}: {}", part, answer),
+        (Format::Json, answer) => {
+            let (elapsed, stream) = match elapsed {
+                Elapsed::Part(value) => (value, false),
+                Elapsed::Stream(value) => (value, true),
+            };
+            let record = Record {
+                day,
+                part,
+                answer,
+                elapsed_ns: elapsed.as_nanos(),
+                stream,
+            };
+            serde_json::to_string(&record).expect("Expected a record to serialize")
+        }
+    };
+}
+
+fn format_bytes(bytes: usize) -> String {
+    return match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1048576.0),
+    };
+}
+
+// Summary of what each step of a run took, one row per step
+pub fn format_stats(steps: &[(u8, String, Usage)]) -> String {
+    let mut table = format!(
+        "{:<4} {:<7} {:>12} {:>10} {:>12}",
+        "Day", "Step", "Time", "Allocs", "Peak heap"
+    );
+    for (day, step, usage) in steps {
+        table.push_str(&format!(
+            "\n{:02}   {:<7} {:>12} {:>10} {:>12}",
+            day,
+            step,
+            format!("{:.1?}", usage.elapsed),
+            usage.allocations,
+            format_bytes(usage.peak_bytes)
+        ));
+    }
+    return table;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn json_records() {
+        let answer = Answer::from(14081365540u64);
+        let elapsed = Elapsed::Part(Duration::from_nanos(1500));
+        assert_eq!(
+            format_result(Format::Json, 11, 2, &answer, elapsed),
+            r#"{"day":11,"part":2,"answer":14081365540,"elapsed_ns":1500}"#
+        );
+    }
+
+    #[test]
+    fn json_image_rows() {
+        let answer = Answer::grid_from("#..\n.#.");
+        let elapsed = Elapsed::Part(Duration::ZERO);
+        assert_eq!(
+            format_result(Format::Json, 10, 2, &answer, elapsed),
+            r##"{"day":10,"part":2,"answer":["#..",".#."],"elapsed_ns":0}"##
+        );
+        assert_eq!(
+            format_result(Format::Text, 10, 2, &answer, elapsed),
+            "  Part 2:\n#..\n.#."
+        );
+    }
+
+    #[test]
+    fn json_streamed_records() {
+        let answer = Answer::from(13140);
+        let elapsed = Elapsed::Stream(Duration::from_nanos(2500));
+        assert_eq!(
+            format_result(Format::Json, 10, 1, &answer, elapsed),
+            r#"{"day":10,"part":1,"answer":13140,"elapsed_ns":2500,"stream":true}"#
+        );
+    }
+
+    #[test]
+    fn stats_table() {
+        let usage = Usage {
+            elapsed: Duration::from_micros(1500),
+            allocations: 12,
+            peak_bytes: 2048,
+        };
+        assert_eq!(
+            format_stats(&[(11, String::from("part 2"), usage)]),
+            "Day  Step            Time     Allocs    Peak heap\n\
+             11   part 2         1.5ms         12      2.0 KiB"
+        );
+    }
+}
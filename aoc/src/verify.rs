@@ -0,0 +1,163 @@
+use crate::{
+    answers::{self, PartAnswers},
+    days::Day,
+};
+use common::input::get_input_path;
+use std::fs;
+
+pub enum Outcome {
+    Pass,
+    // What went wrong, shown below the table
+    Fail(String),
+    // No known answer, or no input to check it with
+    Missing,
+}
+
+pub struct Row {
+    pub day: u8,
+    pub input: &'static str,
+    pub parts: Vec<Outcome>,
+}
+
+impl Outcome {
+    fn label(&self) -> &'static str {
+        return match self {
+            Outcome::Pass => "pass",
+            Outcome::Fail(_) => "fail",
+            Outcome::Missing => "missing",
+        };
+    }
+}
+
+pub fn verify_input(
+    day: &Day,
+    input: &'static str,
+    contents: Option<&str>,
+    expected: &PartAnswers,
+) -> Row {
+    let contents = match contents {
+        Some(value) => value,
+        None => {
+            return Row {
+                day: day.number,
+                input,
+                parts: vec![Outcome::Missing, Outcome::Missing],
+            }
+        }
+    };
+
+    let parts = match (day.run)(contents, &[1, 2]) {
+        Ok(results) => results
+            .parts
+            .into_iter()
+            .map(|result| match (expected.get(result.part), result.answer) {
+                (_, Err(err)) => Outcome::Fail(format!("could not solve, {}", err)),
+                (None, _) => Outcome::Missing,
+                (Some(value), Ok(answer)) if value == answer.to_string() => Outcome::Pass,
+                (Some(value), Ok(answer)) => {
+                    Outcome::Fail(format!("expected {}, got {}", value, answer))
+                }
+            })
+            .collect(),
+        Err(err) => {
+            let message = format!("invalid input at {}", err);
+            vec![Outcome::Fail(message.clone()), Outcome::Fail(message)]
+        }
+    };
+
+    return Row {
+        day: day.number,
+        input,
+        parts,
+    };
+}
+
+// Check every day against its example and its input.txt, printing a table
+// and the details of each failure. Returns whether everything known passed
+pub fn verify_days(days: &[&Day]) -> Result<bool, String> {
+    let mut rows = Vec::new();
+    for day in days {
+        let known = answers::load_answers(day.number)?;
+        rows.push(verify_input(
+            day,
+            "example",
+            Some(day.example),
+            &known.example,
+        ));
+
+        let input = fs::read_to_string(get_input_path(day.number)).ok();
+        rows.push(verify_input(day, "input", input.as_deref(), &known.input));
+    }
+
+    println!("Day  Input    Part 1   Part 2");
+    for row in &rows {
+        let mut line = format!("{:02}   {:<8}", row.day, row.input);
+        for outcome in &row.parts {
+            line.push_str(&format!(" {:<8}", outcome.label()));
+        }
+        println!("{}", line.trim_end());
+    }
+
+    let outcomes = rows.iter().flat_map(|row| row.parts.iter());
+    let count = |label: &str| outcomes.clone().filter(|v| v.label() == label).count();
+    let failed = count("fail");
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count("pass"),
+        failed,
+        count("missing")
+    );
+
+    for row in &rows {
+        for (index, outcome) in row.parts.iter().enumerate() {
+            if let Outcome::Fail(message) = outcome {
+                println!(
+                    "Day {:02} {} part {}: {}",
+                    row.day,
+                    row.input,
+                    index + 1,
+                    message
+                );
+            }
+        }
+    }
+
+    return Ok(failed == 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn example_passes() {
+        let day = days::get_day(1).unwrap();
+        let known = answers::load_answers(1).unwrap();
+        let row = verify_input(day, "example", Some(day.example), &known.example);
+
+        assert!(row.parts.iter().all(|v| matches!(v, Outcome::Pass)));
+    }
+
+    #[test]
+    fn mismatch_fails() {
+        let day = days::get_day(1).unwrap();
+        let expected = answers::parse_answers("[example]\npart_one = 1").unwrap();
+        let row = verify_input(day, "example", Some(day.example), &expected.example);
+
+        match &row.parts[0] {
+            Outcome::Fail(message) => assert_eq!(message, "expected 1, got 24000"),
+            _ => panic!("Expected part 1 to fail"),
+        }
+        assert!(matches!(row.parts[1], Outcome::Missing));
+    }
+
+    #[test]
+    fn no_input_is_missing() {
+        let day = days::get_day(4).unwrap();
+        let known = answers::load_answers(4).unwrap();
+        let row = verify_input(day, "input", None, &known.input);
+
+        assert!(row.parts.iter().all(|v| matches!(v, Outcome::Missing)));
+    }
+}
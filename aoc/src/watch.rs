@@ -0,0 +1,282 @@
+use crate::days::Day;
+use common::input::{get_day_dir, get_workspace_dir};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde_json::Value;
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::Duration,
+};
+
+// Saving a file tends to fire a few events at once, so wait for them to
+// settle before running anything
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+// What changed in each part since the previous run
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change {
+    New(String),
+    Same(String),
+    Changed { previous: String, current: String },
+    Gone(String),
+}
+
+pub type Answers = Vec<(u8, String)>;
+
+// Run cargo as whichever cargo started us, if any
+fn cargo() -> Command {
+    let program = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(program);
+    command.current_dir(get_workspace_dir());
+    return command;
+}
+
+// The day's sources, and its directory for the input and examples. The
+// directory itself is watched rather than each file in it, so files that
+// are created later, or replaced by an editor on save, are still noticed
+pub fn get_watched_paths(day: u8) -> Vec<(PathBuf, RecursiveMode)> {
+    let dir = get_day_dir(day);
+    return vec![
+        (dir.join("src"), RecursiveMode::Recursive),
+        (dir, RecursiveMode::NonRecursive),
+    ];
+}
+
+// Whether a file in the day's directory is its input or one of its examples
+fn is_input_file(name: &str) -> bool {
+    return name == "input.txt" || (name.starts_with("test") && name.ends_with(".txt"));
+}
+
+fn run_tests(day: u8) -> bool {
+    let status = cargo()
+        .args(["test", "--quiet", "-p", &format!("day{:02}", day)])
+        .status();
+    return matches!(status, Ok(status) if status.success());
+}
+
+fn format_answer(answer: &Value) -> String {
+    return match answer {
+        Value::String(text) => text.clone(),
+        Value::Array(rows) => rows
+            .iter()
+            .map(format_answer)
+            .collect::<Vec<String>>()
+            .join("\n"),
+        value => value.to_string(),
+    };
+}
+
+// Solve the day's input.txt through a freshly built runner, since this one
+// still has the old code in it
+fn run_solver(day: u8) -> Result<Answers, String> {
+    let output = cargo()
+        .args(["run", "--quiet", "-p", "aoc", "--", "run"])
+        .args([day.to_string().as_str(), "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| format!("Could not run cargo: {}", err))?;
+    if !output.status.success() {
+        return Err(String::from("Expected the solver to finish"));
+    }
+
+    let mut answers = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let record: Value = serde_json::from_str(line)
+            .map_err(|err| format!("Expected a JSON record, got '{}': {}", line, err))?;
+        let part = record["part"].as_u64().unwrap_or(0) as u8;
+        answers.push((part, format_answer(&record["answer"])));
+    }
+    return Ok(answers);
+}
+
+pub fn diff_answers(previous: &Answers, current: &Answers) -> Vec<(u8, Change)> {
+    let mut changes = Vec::new();
+    for (part, answer) in current {
+        let change = match previous.iter().find(|(v, _)| v == part) {
+            None => Change::New(answer.clone()),
+            Some((_, old)) if old == answer => Change::Same(answer.clone()),
+            Some((_, old)) => Change::Changed {
+                previous: old.clone(),
+                current: answer.clone(),
+            },
+        };
+        changes.push((*part, change));
+    }
+    for (part, answer) in previous {
+        if !current.iter().any(|(v, _)| v == part) {
+            changes.push((*part, Change::Gone(answer.clone())));
+        }
+    }
+    changes.sort_by_key(|(part, _)| *part);
+    return changes;
+}
+
+// Multi-line answers go below their label
+fn format_change(part: u8, change: &Change) -> String {
+    let label = format!("  Part {}", part);
+    let join = |answer: &str| match answer.contains('\n') {
+        true => format!("\n{}", answer),
+        false => format!(" {}", answer),
+    };
+    return match change {
+        Change::New(answer) => format!("{}:{}", label, join(answer)),
+        Change::Same(answer) => format!("{} (unchanged):{}", label, join(answer)),
+        Change::Changed { previous, current } => format!(
+            "{} (changed):{}\n  was:{}",
+            label,
+            join(current),
+            join(previous)
+        ),
+        Change::Gone(answer) => format!("{} (no answer):\n  was:{}", label, join(answer)),
+    };
+}
+
+fn run_once(day: u8, previous: &mut Option<Answers>) {
+    println!("Testing day {:02}", day);
+    if !run_tests(day) {
+        println!("Tests failed");
+    }
+
+    println!("Solving day {:02}", day);
+    let answers = match run_solver(day) {
+        Ok(value) => value,
+        Err(err) => {
+            println!("{}", err);
+            return;
+        }
+    };
+    let changes = diff_answers(previous.as_ref().unwrap_or(&Vec::new()), &answers);
+    for (part, change) in changes {
+        println!("{}", format_change(part, &change));
+    }
+    *previous = Some(answers);
+}
+
+// Whether an event is worth re-running for: a change to the day's sources,
+// input or examples. Reads and anything else in its directory aren't
+fn is_relevant(event: &Event, dir: &Path) -> bool {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return false;
+    }
+    return event.paths.iter().any(|changed| {
+        if changed.starts_with(dir.join("src")) {
+            return true;
+        }
+        let name = changed.file_name().map(|name| name.to_string_lossy());
+        return changed.parent() == Some(dir) && name.is_some_and(|name| is_input_file(&name));
+    });
+}
+
+fn describe(paths: &[PathBuf]) -> String {
+    let root = get_workspace_dir();
+    return paths
+        .iter()
+        .map(|path| path.strip_prefix(&root).unwrap_or(path))
+        .map(Path::display)
+        .map(|path| path.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+}
+
+// Run the tests and the solver, then again every time one of the day's files
+// changes, until interrupted
+pub fn watch(day: &Day) -> Result<(), String> {
+    let watched = get_watched_paths(day.number);
+    let dir = get_day_dir(day.number);
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)
+        .map_err(|err| format!("Could not start watching: {}", err))?;
+    for (path, mode) in &watched {
+        watcher
+            .watch(path, *mode)
+            .map_err(|err| format!("Could not watch {}: {}", path.display(), err))?;
+    }
+
+    let mut previous = None;
+    println!(
+        "Watching {} and the input and examples in {}",
+        describe(&[dir.join("src")]),
+        describe(std::slice::from_ref(&dir))
+    );
+    run_once(day.number, &mut previous);
+
+    loop {
+        let event = match receiver.recv() {
+            Ok(Ok(value)) => value,
+            Ok(Err(err)) => return Err(format!("Could not watch for changes: {}", err)),
+            Err(_) => return Ok(()),
+        };
+        if !is_relevant(&event, &dir) {
+            continue;
+        }
+
+        let mut changed = event.paths;
+        while let Ok(Ok(event)) = receiver.recv_timeout(SETTLE_TIME) {
+            if is_relevant(&event, &dir) {
+                changed.extend(event.paths);
+            }
+        }
+        changed.sort();
+        changed.dedup();
+
+        println!("\nChanged {}", describe(&changed));
+        run_once(day.number, &mut previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, ModifyKind};
+
+    #[test]
+    fn diff_against_previous_run() {
+        let previous = vec![(1, String::from("24000")), (2, String::from("45000"))];
+        let current = vec![(1, String::from("24000")), (2, String::from("41000"))];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            vec![
+                (1, Change::Same(String::from("24000"))),
+                (
+                    2,
+                    Change::Changed {
+                        previous: String::from("45000"),
+                        current: String::from("41000")
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            diff_answers(&Vec::new(), &current[..1].to_vec()),
+            vec![(1, Change::New(String::from("24000")))]
+        );
+    }
+
+    #[test]
+    fn watches_sources_and_inputs() {
+        let dir = get_day_dir(9);
+        assert_eq!(
+            get_watched_paths(9),
+            vec![
+                (dir.join("src"), RecursiveMode::Recursive),
+                (dir.clone(), RecursiveMode::NonRecursive),
+            ]
+        );
+
+        let modified =
+            |path: PathBuf| Event::new(EventKind::Modify(ModifyKind::Any)).add_path(path);
+        assert!(is_relevant(&modified(dir.join("src/lib.rs")), &dir));
+        assert!(is_relevant(&modified(dir.join("input.txt")), &dir));
+        // An example that didn't exist when watching started
+        assert!(is_relevant(&modified(dir.join("test3.txt")), &dir));
+        assert!(!is_relevant(&modified(dir.join("Cargo.toml")), &dir));
+        assert!(!is_relevant(&modified(dir.join("rules/test.txt")), &dir));
+
+        let read = Event::new(EventKind::Access(AccessKind::Any)).add_path(dir.join("input.txt"));
+        assert!(!is_relevant(&read, &dir));
+    }
+}
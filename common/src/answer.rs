@@ -0,0 +1,62 @@
+use serde::Serialize;
+use std::fmt;
+
+// Integers cover every numeric answer, from day10's signed register to
+// day11's monkey business, without losing precision. Serialized as a bare
+// number, string or array of rows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    // Render an image, one row per line, as a grid
+    pub fn grid_from(image: &str) -> Self {
+        return Answer::Grid(image.lines().map(|v| v.to_string()).collect());
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    return Answer::Integer(value.into());
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        return Answer::Integer(value as i128);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        return Answer::Text(value);
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        return Answer::Text(value.to_string());
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
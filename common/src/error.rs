@@ -0,0 +1,94 @@
+use std::{error, fmt};
+
+// Where a puzzle input stopped making sense. Lines and columns start at 1,
+// and text holds the whole offending line so it can be shown back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        return ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        };
+    }
+
+    // Error pointing at a token, which must be a slice of the given line
+    pub fn at(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= text.len())
+            .unwrap_or(0);
+        let column = text[..offset].chars().count() + 1;
+        return Self::new(line, column, text, message);
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let number = self.line.to_string();
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(
+            f,
+            "{} | {}^",
+            " ".repeat(number.len()),
+            " ".repeat(self.column.saturating_sub(1))
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+// Why a part couldn't be solved from an input that did parse, like an answer
+// too large to be counted. Never shown as an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        return SolveError {
+            message: message.into(),
+        };
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for SolveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_from_token() {
+        let text = "move 1 from x to 3";
+        let error = ParseError::at(4, text, &text[12..13], "Expected a number");
+
+        assert_eq!(error.line, 4);
+        assert_eq!(error.column, 13);
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 13: Expected a number\n4 | move 1 from x to 3\n  |             ^"
+        );
+    }
+}
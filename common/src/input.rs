@@ -0,0 +1,176 @@
+use std::{
+    env, error, fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+    process,
+};
+
+// The workspace root, where every day crate lives. Found from this crate's
+// place in the workspace, so it works from any binary, test or bench
+pub fn get_workspace_dir() -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.pop();
+    return path;
+}
+
+pub fn get_day_dir(day: u8) -> PathBuf {
+    let mut path = get_workspace_dir();
+    path.push(format!("day{:02}", day));
+    return path;
+}
+
+// Inputs live next to each day crate, at the workspace root
+pub fn get_input_path(day: u8) -> PathBuf {
+    let mut path = get_day_dir(day);
+    path.push("input.txt");
+    return path;
+}
+
+// Where a puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Example,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl InputSource {
+    // A path, - for stdin, or --example for the bundled test.txt
+    pub fn from_arg(arg: &str) -> Self {
+        return match arg {
+            "-" => InputSource::Stdin,
+            "--example" => InputSource::Example,
+            path => InputSource::File(PathBuf::from(path)),
+        };
+    }
+
+    pub fn read(&self, example: &str) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::Example => Ok(example.to_string()),
+        };
+        return result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        });
+    }
+
+    // Open the input to be read bit by bit, instead of all at once
+    pub fn open(&self, example: &'static str) -> Result<Box<dyn BufRead>, InputError> {
+        return match self {
+            InputSource::File(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(InputError {
+                    source: self.clone(),
+                    error,
+                }),
+            },
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Example => Ok(Box::new(example.as_bytes())),
+        };
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Example => write!(f, "example"),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.source, self.error.kind()) {
+            (InputSource::File(path), io::ErrorKind::NotFound) => {
+                write!(f, "Expected input file at {}", path.display())
+            }
+            (source, _) => write!(f, "Could not read input from {}: {}", source, self.error),
+        }
+    }
+}
+
+impl error::Error for InputError {}
+
+// Read the input for a day's binary: the first command line argument picks
+// the source, and input.txt in the current directory is used otherwise.
+// Exits with a message if the input can't be read
+pub fn read_from_args(example: &str) -> (InputSource, String) {
+    let (source, input, _) = read_from_args_with(example, &[]);
+    return (source, input);
+}
+
+// Same as read_from_args, for binaries that also take some flags of their
+// own, in any order. Returns the flags that were given
+pub fn read_from_args_with(
+    example: &str,
+    flags: &[&'static str],
+) -> (InputSource, String, Vec<&'static str>) {
+    let (source, input, given, _) = read_from_args_with_options(example, flags, &[]);
+    return (source, input, given);
+}
+
+// Same as read_from_args_with, for binaries that also take options followed
+// by a value, like --rules <path>. Returns the flags and the options that
+// were given, with their values
+pub fn read_from_args_with_options(
+    example: &str,
+    flags: &[&'static str],
+    options: &[&'static str],
+) -> (
+    InputSource,
+    String,
+    Vec<&'static str>,
+    Vec<(&'static str, String)>,
+) {
+    let mut source = None;
+    let mut given = Vec::new();
+    let mut values = Vec::new();
+    let usage = || {
+        let flags: String = flags.iter().map(|flag| format!(" [{}]", flag)).collect();
+        let options: String = options
+            .iter()
+            .map(|option| format!(" [{} <value>]", option))
+            .collect();
+        eprintln!("Usage: [<input path> | - | --example]{}{}", flags, options);
+        process::exit(2);
+    };
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if let Some(flag) = flags.iter().find(|flag| **flag == arg) {
+            given.push(*flag);
+        } else if let Some(option) = options.iter().find(|option| **option == arg) {
+            match args.next() {
+                Some(value) => values.push((*option, value)),
+                None => usage(),
+            }
+        } else if source.is_none() && (arg == "-" || arg == "--example" || !arg.starts_with('-')) {
+            source = Some(InputSource::from_arg(&arg));
+        } else {
+            usage();
+        }
+    }
+    let source = source.unwrap_or_else(|| InputSource::File(PathBuf::from("./input.txt")));
+
+    match source.read(example) {
+        Ok(input) => return (source, input, given, values),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
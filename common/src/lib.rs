@@ -0,0 +1,61 @@
+mod answer;
+mod error;
+pub mod input;
+pub mod log;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+pub mod stream;
+
+pub use answer::Answer;
+pub use error::{ParseError, SolveError};
+pub use stream::{StreamError, Streaming};
+
+// Every day parses its puzzle input once, then solves both parts from it.
+// A part fails instead of giving a wrong answer when it can't be counted
+pub trait Solution {
+    const DAY: u8;
+    // The example from the puzzle description, bundled as test.txt
+    const EXAMPLE: &'static str;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError>;
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError>;
+}
+
+// A test solving a day's example through its Solution, for its tests/api.rs.
+// The answers are anything that turns into an Answer
+#[macro_export]
+macro_rules! solution_on_example {
+    ($day:ty, $one:expr, $two:expr $(,)?) => {
+        #[test]
+        fn solution_on_example() {
+            let input =
+                <$day as $crate::Solution>::parse(<$day as $crate::Solution>::EXAMPLE).unwrap();
+
+            assert_eq!(
+                <$day as $crate::Solution>::part_one(&input),
+                Ok($crate::Answer::from($one))
+            );
+            assert_eq!(
+                <$day as $crate::Solution>::part_two(&input),
+                Ok($crate::Answer::from($two))
+            );
+        }
+    };
+}
+
+// A generated input, and both its answers
+pub type Generated = (String, (Answer, Answer));
+
+// Random puzzle inputs, to stress test and benchmark the solvers well past
+// the size of a real input. The same seed always gives the same input.
+// Each day works out the answers while building the input, planting them
+// where it can, so they can be checked without trusting the solver
+pub trait Generator: Solution {
+    // About the size of a real puzzle input
+    const INPUT_SIZE: usize;
+
+    fn generate(seed: u64, size: usize) -> Generated;
+}
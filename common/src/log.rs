@@ -0,0 +1,21 @@
+use std::io::{self, IsTerminal};
+use tracing_subscriber::EnvFilter;
+
+// Show what the days log with tracing. It goes to stderr, so answers can
+// still be piped, and RUST_LOG picks what's shown, like RUST_LOG=day12=trace.
+// Only warnings are shown otherwise
+pub fn init() {
+    init_with("warn");
+}
+
+// Same as init, showing what default_filter picks when RUST_LOG isn't set
+pub fn init_with(default_filter: &str) {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_filter));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
@@ -0,0 +1,35 @@
+// Snapshot tests of what each day's binary prints, for its tests/output.rs.
+// Everything the day prints for its example is kept in tests/snapshots, so a
+// change shows up as a diff against it, to accept with `cargo insta review`
+use std::process::Command;
+
+pub use insta;
+
+// Run a day's binary on its example, with some flags of its own, and return
+// what it printed. Panics if it fails, as it's only meant for tests
+pub fn run_example(binary: &str, flags: &[&str]) -> String {
+    let output = Command::new(binary)
+        .arg("--example")
+        .args(flags)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{} failed on its example", binary);
+    return String::from_utf8(output.stdout).unwrap();
+}
+
+// A test snapshotting what a day's binary prints for its example, as
+// example_output, or as the given test with some flags
+#[macro_export]
+macro_rules! snapshot_example_output {
+    ($binary:expr) => {
+        $crate::snapshot_example_output!(example_output, $binary, []);
+    };
+    ($name:ident, $binary:expr, [$($flag:expr),* $(,)?]) => {
+        #[test]
+        fn $name() {
+            let output = $crate::snapshot::run_example($binary, &[$($flag),*]);
+            $crate::snapshot::insta::assert_snapshot!(output);
+        }
+    };
+}
@@ -0,0 +1,92 @@
+use crate::{Answer, ParseError, Solution, SolveError};
+use std::{
+    error, fmt,
+    io::{self, BufRead},
+};
+
+// Why a streamed input couldn't be solved: it couldn't be read, it stopped
+// making sense part way through, or an answer couldn't be counted
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+// Days whose input can be read a line at a time are also solved straight
+// from a reader, without ever holding the whole input, giving the same
+// answers as parsing it whole. Both parts are solved in a single pass
+pub trait Streaming: Solution {
+    fn solve_stream(reader: impl BufRead) -> Result<(Answer, Answer), StreamError>;
+}
+
+// Every line of a reader, with its line ending removed the same way
+// str::lines does
+pub fn lines(reader: impl BufRead) -> impl Iterator<Item = Result<String, StreamError>> {
+    return reader.lines().map(|line| line.map_err(StreamError::Io));
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        return StreamError::Parse(error);
+    }
+}
+
+impl From<SolveError> for StreamError {
+    fn from(error: SolveError) -> Self {
+        return StreamError::Solve(error);
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        return StreamError::Io(error);
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "Could not keep reading input: {}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+            StreamError::Solve(error) => write!(f, "Could not solve input: {}", error),
+        }
+    }
+}
+
+impl error::Error for StreamError {}
+
+// A test solving a day's example, and any other inputs given, both streamed
+// and parsed whole, expecting the same answers. The reader's buffer is kept
+// tiny so lines are split across reads. With the day's generate feature, a
+// generated input is solved as well
+#[macro_export]
+macro_rules! stream_matches_parse {
+    ($day:ty $(, $input:expr)* $(,)?) => {
+        #[test]
+        fn stream_matches_parse() {
+            #[cfg_attr(not(feature = "generate"), allow(unused_mut))]
+            let mut inputs: Vec<String> = vec![
+                <$day as $crate::Solution>::EXAMPLE.to_string(),
+                $($input.to_string(),)*
+            ];
+            #[cfg(feature = "generate")]
+            inputs.push(
+                <$day as $crate::Generator>::generate(1, <$day as $crate::Generator>::INPUT_SIZE).0,
+            );
+            for input in inputs {
+                let parsed = <$day as $crate::Solution>::parse(&input).unwrap();
+                let reader = std::io::BufReader::with_capacity(7, input.as_bytes());
+
+                let streamed = <$day as $crate::Streaming>::solve_stream(reader).unwrap();
+                assert_eq!(
+                    streamed,
+                    (
+                        <$day as $crate::Solution>::part_one(&parsed).unwrap(),
+                        <$day as $crate::Solution>::part_two(&parsed).unwrap()
+                    )
+                );
+            }
+        }
+    };
+}
@@ -0,0 +1,114 @@
+use common::{Answer, ParseError, StreamError};
+use std::{error, fmt};
+
+/// Calories of an item, or of all the items an elf carries. Counted in 64
+/// bits, or without any limit with the bignum feature
+#[cfg(not(feature = "bignum"))]
+pub type Calories = u64;
+/// Calories of an item, or of all the items an elf carries. Counted in 64
+/// bits, or without any limit with the bignum feature
+#[cfg(feature = "bignum")]
+pub type Calories = num_bigint::BigUint;
+
+/// What was wrong with a line of the inventories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaloriesErrorKind {
+    /// The line isn't a number of calories
+    NotANumber,
+    /// The item, or the elf's total once the item is added, is too many
+    /// calories to count
+    Overflow,
+}
+
+/// A line of the inventories that couldn't be counted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaloriesError {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// The whole line
+    pub text: String,
+    /// What was wrong with it
+    pub kind: CaloriesErrorKind,
+}
+
+impl From<CaloriesError> for ParseError {
+    fn from(err: CaloriesError) -> Self {
+        let message = match err.kind {
+            CaloriesErrorKind::NotANumber => "Expected the calories of an item",
+            CaloriesErrorKind::Overflow => "Expected the elf's total calories to fit in 64 bits",
+        };
+        return ParseError::new(err.line, 1, &err.text, message);
+    }
+}
+
+impl From<CaloriesError> for StreamError {
+    fn from(err: CaloriesError) -> Self {
+        return StreamError::Parse(err.into());
+    }
+}
+
+impl fmt::Display for CaloriesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ParseError::from(self.clone()))
+    }
+}
+
+impl error::Error for CaloriesError {}
+
+// The calories of an item, on a line of its own
+pub(crate) fn parse(line: usize, text: &str) -> Result<Calories, CaloriesError> {
+    let error = |kind| CaloriesError {
+        line,
+        text: text.to_string(),
+        kind,
+    };
+    // Only digits, so the only way for a number to fail is being too large
+    if text.is_empty() || !text.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(error(CaloriesErrorKind::NotANumber));
+    }
+    return text
+        .parse::<Calories>()
+        .map_err(|_| error(CaloriesErrorKind::Overflow));
+}
+
+#[cfg(not(feature = "bignum"))]
+pub(crate) fn checked_add(total: &Calories, item: &Calories) -> Option<Calories> {
+    return total.checked_add(*item);
+}
+
+#[cfg(feature = "bignum")]
+pub(crate) fn checked_add(total: &Calories, item: &Calories) -> Option<Calories> {
+    return Some(total + item);
+}
+
+#[cfg(not(feature = "bignum"))]
+pub(crate) fn to_f64(calories: &Calories) -> f64 {
+    return *calories as f64;
+}
+
+// Totals too large for a float become infinite
+#[cfg(feature = "bignum")]
+pub(crate) fn to_f64(calories: &Calories) -> f64 {
+    return num_traits::ToPrimitive::to_f64(calories).unwrap_or(f64::INFINITY);
+}
+
+pub(crate) fn difference(higher: &Calories, lower: &Calories) -> Calories {
+    return higher - lower;
+}
+
+/// The sum of some totals, as an answer. Unlike adding them up as
+/// [`Calories`], it can't overflow
+#[cfg(not(feature = "bignum"))]
+pub fn get_calories_sum(calories: &[Calories]) -> Answer {
+    return Answer::Integer(calories.iter().map(|v| *v as i128).sum());
+}
+
+/// The sum of some totals, as an answer. Too large ones are shown as text
+#[cfg(feature = "bignum")]
+pub fn get_calories_sum(calories: &[Calories]) -> Answer {
+    let sum: Calories = calories.iter().sum();
+    return match i128::try_from(&sum) {
+        Ok(value) => Answer::Integer(value),
+        Err(_) => Answer::Text(sum.to_string()),
+    };
+}
@@ -0,0 +1,51 @@
+use crate::Day01;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// The size is the number of elves. Their totals are kept as they're made up,
+// so the answers are known without reading the input back
+impl Generator for Day01 {
+    const INPUT_SIZE: usize = 250;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut elves = Vec::new();
+        let mut totals = Vec::new();
+
+        for _ in 0..size.max(1) {
+            let items: Vec<u64> = (0..rng.gen_range(1..=15))
+                .map(|_| rng.gen_range(1000..=60000))
+                .collect();
+            totals.push(items.iter().sum::<u64>());
+            let items: Vec<String> = items.iter().map(|v| v.to_string()).collect();
+            elves.push(items.join("\n"));
+        }
+
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+        let answers = (
+            Answer::from(totals[0]),
+            Answer::from(totals.iter().take(3).sum::<u64>()),
+        );
+        return (elves.join("\n\n") + "\n", answers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day01::generate(7, 100);
+        assert_eq!(input, Day01::generate(7, 100).0);
+
+        let inventories = Day01::parse(&input).unwrap();
+        assert_eq!(inventories.len(), 100);
+
+        let (one, two) = answers;
+        assert_eq!(Day01::part_one(&inventories), Ok(one));
+        assert_eq!(Day01::part_two(&inventories), Ok(two));
+    }
+}
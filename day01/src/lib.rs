@@ -0,0 +1,423 @@
+//! Day 1: Calorie Counting
+//!
+//! Elves list the calories of the items they carry, one per line, with a
+//! blank line between elves. Finds the elf carrying the most calories, and
+//! the three carrying the most together.
+#![warn(missing_docs)]
+
+use common::{stream, Answer, ParseError, Solution, SolveError, StreamError, Streaming};
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+use tracing::debug;
+
+mod calories;
+#[cfg(feature = "generate")]
+mod generate;
+mod stats;
+
+pub use calories::{get_calories_sum, Calories, CaloriesError, CaloriesErrorKind};
+pub use stats::{get_stats, Bucket, Stats, StatsError};
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day01;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// An elf's inventory: the calories of each item they carry, and their
+/// total, which is checked to be countable when the inventory is made
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    items: Vec<Calories>,
+    total: Calories,
+}
+
+impl Inventory {
+    /// An inventory of some items, none if they add up to more calories than
+    /// can be counted
+    pub fn new(items: Vec<Calories>) -> Option<Inventory> {
+        let mut total = Calories::default();
+        for item in &items {
+            total = calories::checked_add(&total, item)?;
+        }
+        return Some(Inventory { items, total });
+    }
+
+    /// The calories of each item
+    pub fn items(&self) -> &[Calories] {
+        return &self.items;
+    }
+
+    /// The calories of all the items
+    pub fn total(&self) -> &Calories {
+        return &self.total;
+    }
+}
+
+/// Every elf's inventory
+pub type Inventories = Vec<Inventory>;
+
+// Read the items line by line, calling f with each elf's inventory and its
+// total. Totals are checked as they're added up, so an elf that carries too
+// much is an error rather than a wrong answer.
+// Lines are trimmed, so a \r left by Windows line endings or trailing spaces
+// don't get in the way, and a run of blank lines separates elves just like a
+// single one does, without adding elves carrying nothing
+fn iterate_input<L: AsRef<str>, E: From<CaloriesError>>(
+    lines: impl Iterator<Item = Result<L, E>>,
+    mut f: impl FnMut(Vec<Calories>, Calories),
+) -> Result<(), E> {
+    let mut items = Vec::new();
+    let mut total = Calories::default();
+    let mut elf = 1;
+
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref().trim();
+        if line.is_empty() {
+            if !items.is_empty() {
+                debug!(elf, items = items.len(), %total, "elf's calories");
+                f(items, total);
+                elf += 1;
+                items = Vec::new();
+                total = Calories::default();
+            }
+            continue;
+        }
+        let calories = calories::parse(i + 1, line)?;
+        total = calories::checked_add(&total, &calories).ok_or_else(|| CaloriesError {
+            line: i + 1,
+            text: line.to_string(),
+            kind: CaloriesErrorKind::Overflow,
+        })?;
+        items.push(calories);
+    }
+    if !items.is_empty() {
+        debug!(elf, items = items.len(), %total, "elf's calories");
+        f(items, total);
+    }
+    return Ok(());
+}
+
+/// Read each elf's items, failing on anything that isn't a number of calories
+/// and on any elf carrying more calories than can be counted. Windows line
+/// endings, whitespace around the calories and runs of blank lines are all
+/// accepted
+pub fn parse_input(input: &str) -> Result<Inventories, CaloriesError> {
+    let mut inventories = Vec::new();
+    iterate_input(input.lines().map(Ok), |items, total| {
+        inventories.push(Inventory { items, total })
+    })?;
+    return Ok(inventories);
+}
+
+/// The most calories carried by a single elf
+pub fn get_max_calories(inventories: &[Inventory]) -> Calories {
+    let mut max_calories = Calories::default();
+
+    for (i, inventory) in inventories.iter().enumerate() {
+        let curr_calories = inventory.total().to_owned();
+        if curr_calories > max_calories {
+            debug!(
+                elf = i + 1,
+                calories = %curr_calories,
+                "most calories so far"
+            );
+            max_calories = curr_calories;
+        }
+    }
+    return max_calories;
+}
+
+// Keeps the n elves carrying the most calories seen so far, in a min-heap
+// so the one to drop is always on top. Of two elves carrying as much, the
+// first one ranks higher
+struct TopElves {
+    n: usize,
+    heap: BinaryHeap<Reverse<(Calories, Reverse<usize>)>>,
+}
+
+impl TopElves {
+    fn new(n: usize) -> Self {
+        return TopElves {
+            n,
+            heap: BinaryHeap::with_capacity(n.saturating_add(1)),
+        };
+    }
+
+    fn push(&mut self, index: usize, calories: Calories) {
+        let elf = Reverse((calories, Reverse(index)));
+        if self.heap.len() < self.n {
+            self.heap.push(elf);
+        } else if let Some(mut lowest) = self.heap.peek_mut() {
+            if elf < *lowest {
+                *lowest = elf;
+            }
+        }
+    }
+
+    // Highest first
+    fn into_sorted_vec(self) -> Vec<(usize, Calories)> {
+        return self
+            .heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| (index, calories))
+            .collect();
+    }
+}
+
+/// The n elves carrying the most calories, highest first, as their index in
+/// inventories and their total. Fewer if there aren't n elves
+pub fn top_n(inventories: &[Inventory], n: usize) -> Vec<(usize, Calories)> {
+    // There's no room to make for more elves than there are
+    let mut top_elves = TopElves::new(n.min(inventories.len()));
+    for (i, inventory) in inventories.iter().enumerate() {
+        top_elves.push(i, inventory.total().to_owned());
+    }
+    return top_elves.into_sorted_vec();
+}
+
+/// The calories carried by the three elves carrying the most, highest first.
+/// Missing elves carry 0
+pub fn get_top_calories(inventories: &[Inventory]) -> [Calories; 3] {
+    let mut top_elves = top_n(inventories, 3).into_iter();
+    let result = std::array::from_fn(|_| top_elves.next().unwrap_or_default().1);
+    debug!(calories = ?result, "top three");
+
+    return result;
+}
+
+/// How an elf compares to the others
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfReport {
+    /// Index of the elf in the inventories
+    pub index: usize,
+    /// How many items the elf carries
+    pub items: usize,
+    /// Calories of all those items
+    pub total: Calories,
+    /// 1 for the elf carrying the most, ties going to the first elf
+    pub rank: usize,
+    /// Calories short of the elf ranked right above, none for the first
+    pub gap: Option<Calories>,
+}
+
+/// Every elf, ranked by the calories they carry
+pub fn get_report(inventories: &[Inventory]) -> Vec<ElfReport> {
+    let ranked = top_n(inventories, inventories.len());
+
+    let mut report: Vec<ElfReport> = Vec::with_capacity(ranked.len());
+    for (i, (index, total)) in ranked.into_iter().enumerate() {
+        let gap = report
+            .last()
+            .map(|above| calories::difference(&above.total, &total));
+        report.push(ElfReport {
+            index,
+            items: inventories[index].items().len(),
+            total,
+            rank: i + 1,
+            gap,
+        });
+    }
+    return report;
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Inventories;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Ok(parse_input(input)?);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_calories_sum(&[get_max_calories(input)]));
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_calories_sum(&get_top_calories(input)));
+    }
+}
+
+impl Streaming for Day01 {
+    fn solve_stream(reader: impl BufRead) -> Result<(Answer, Answer), StreamError> {
+        // The elf carrying the most is the first of the top three
+        let mut top_elves = TopElves::new(3);
+        let mut index = 0;
+
+        iterate_input(stream::lines(reader), |_, total| {
+            top_elves.push(index, total);
+            index += 1;
+        })?;
+
+        let top_calories: Vec<Calories> = top_elves
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(_, calories)| calories)
+            .collect();
+        let max_calories = top_calories.first().cloned().unwrap_or_default();
+        return Ok((
+            get_calories_sum(&[max_calories]),
+            get_calories_sum(&top_calories),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calories(value: u32) -> Calories {
+        return Calories::from(value);
+    }
+
+    fn inventory(items: &[u32]) -> Inventory {
+        return Inventory::new(items.iter().map(|v| calories(*v)).collect()).unwrap();
+    }
+
+    #[test]
+    fn max_calories() {
+        let inventories = parse_input(EXAMPLE).unwrap();
+        let max_calories = get_max_calories(&inventories);
+        assert_eq!(max_calories, calories(24000));
+    }
+    #[test]
+    fn top_calories() {
+        let inventories = parse_input(EXAMPLE).unwrap();
+        let top_calories = get_top_calories(&inventories);
+
+        assert_eq!(get_calories_sum(&top_calories), Answer::Integer(45000));
+    }
+    #[test]
+    fn top_elves() {
+        let inventories = parse_input(EXAMPLE).unwrap();
+        let elves = |elves: &[(usize, u32)]| -> Vec<(usize, Calories)> {
+            return elves.iter().map(|(i, v)| (*i, calories(*v))).collect();
+        };
+
+        assert_eq!(top_n(&inventories, 2), elves(&[(3, 24000), (2, 11000)]));
+        assert_eq!(top_n(&inventories, 0), vec![]);
+        assert_eq!(top_n(&inventories, 9).len(), 5);
+        assert_eq!(top_n(&inventories, usize::MAX).len(), 5);
+        assert_eq!(top_n(&[], usize::MAX), vec![]);
+
+        let tied = vec![inventory(&[5]), inventory(&[5]), inventory(&[1])];
+        assert_eq!(top_n(&tied, 2), elves(&[(0, 5), (1, 5)]));
+    }
+    #[test]
+    fn report() {
+        let inventories = parse_input(EXAMPLE).unwrap();
+        let report = get_report(&inventories);
+
+        assert_eq!(
+            report[1],
+            ElfReport {
+                index: 2,
+                items: 2,
+                total: calories(11000),
+                rank: 2,
+                gap: Some(calories(13000)),
+            }
+        );
+        assert_eq!(report[0].gap, None);
+        assert_eq!(
+            report.iter().map(|v| v.index).collect::<Vec<usize>>(),
+            vec![3, 2, 4, 0, 1]
+        );
+    }
+    #[test]
+    fn invalid_calories() {
+        let error = parse_input("1000\n2000\n\n30x0\n").unwrap_err();
+
+        assert_eq!(error.line, 4);
+        assert_eq!(error.text, "30x0");
+        assert_eq!(error.kind, CaloriesErrorKind::NotANumber);
+
+        let error = ParseError::from(parse_input("1000\n-5\n").unwrap_err());
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Expected the calories of an item");
+    }
+    #[test]
+    fn windows_line_endings() {
+        let inventories = parse_input(&EXAMPLE.replace('\n', "\r\n")).unwrap();
+        assert_eq!(inventories, parse_input(EXAMPLE).unwrap());
+
+        // A lone \r, as left by a reader splitting on \n only
+        let inventories = parse_input("1000\r\n2000\r\r\n\r\n3000\r").unwrap();
+        assert_eq!(
+            inventories,
+            vec![inventory(&[1000, 2000]), inventory(&[3000])]
+        );
+    }
+    #[test]
+    fn surrounding_whitespace() {
+        let inventories = parse_input("  1000 \n2000\t\n \t\n3000  \n").unwrap();
+        assert_eq!(
+            inventories,
+            vec![inventory(&[1000, 2000]), inventory(&[3000])]
+        );
+
+        // Whitespace inside a number is still an error
+        let error = parse_input("10 00\n").unwrap_err();
+        assert_eq!((error.line, error.kind), (1, CaloriesErrorKind::NotANumber));
+    }
+    #[test]
+    fn runs_of_blank_lines() {
+        let inventories = parse_input("\n\n1000\n\n\n\n2000\n3000\n\n\n").unwrap();
+        assert_eq!(
+            inventories,
+            vec![inventory(&[1000]), inventory(&[2000, 3000])]
+        );
+        assert_eq!(
+            get_top_calories(&inventories),
+            [5000u32, 1000, 0].map(Calories::from)
+        );
+
+        assert_eq!(parse_input("\n\n").unwrap(), Inventories::new());
+
+        // Lines are still counted from the top of the input
+        let error = parse_input("1000\n\n\n\nfood\n").unwrap_err();
+        assert_eq!(error.line, 5);
+    }
+    #[test]
+    #[cfg(not(feature = "bignum"))]
+    fn calories_overflow() {
+        // Too much for 32 bits, which used to be an error
+        let inventories = parse_input("4000000000\n300000000\n").unwrap();
+        assert_eq!(get_max_calories(&inventories), 4300000000);
+
+        let error = parse_input("1\n\n18446744073709551615\n1\n").unwrap_err();
+        assert_eq!((error.line, error.kind), (4, CaloriesErrorKind::Overflow));
+
+        // Inventories made by hand are checked the same way
+        assert_eq!(Inventory::new(vec![u64::MAX, 1]), None);
+        assert_eq!(Inventory::new(vec![u64::MAX]).unwrap().total(), &u64::MAX);
+
+        let error = parse_input("18446744073709551616\n").unwrap_err();
+        assert_eq!((error.line, error.kind), (1, CaloriesErrorKind::Overflow));
+
+        // The sum of the top three can still go past 64 bits
+        let inventories = parse_input("18446744073709551615\n\n18446744073709551615\n").unwrap();
+        assert_eq!(
+            Day01::part_two(&inventories),
+            Ok(Answer::Integer(2 * u64::MAX as i128))
+        );
+    }
+    #[test]
+    #[cfg(feature = "bignum")]
+    fn calories_without_limit() {
+        let inventories = parse_input("18446744073709551615\n1\n").unwrap();
+        assert_eq!(
+            get_max_calories(&inventories).to_string(),
+            "18446744073709551616"
+        );
+
+        let huge = "9".repeat(40);
+        let inventories = parse_input(&format!("{}\n\n1\n", huge)).unwrap();
+        assert_eq!(Day01::part_one(&inventories), Ok(Answer::Text(huge)));
+    }
+    common::stream_matches_parse!(
+        Day01,
+        format!("\r\n{}\r\n\r\n", EXAMPLE.replace("\n\n", " \r\n\r\n\t\r\n"))
+    );
+}
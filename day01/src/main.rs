@@ -1,84 +1,94 @@
-use std::fs;
-
-fn iterate_input(input: &String, mut f: impl FnMut(u32) -> ()) {
-    let mut curr_calories = 0;
-
-    let mut word = String::new();
-
-    for c in input.chars() {
-        if c == '\n' {
-            if !word.is_empty() {
-                curr_calories += word.parse::<u32>().unwrap();
-                word.clear();
-                continue;
-            }
-            f(curr_calories);
-            curr_calories = 0;
-            word.clear();
-            continue;
-        }
-        word.push(c);
+use common::{input, log};
+use std::process;
+
+use day01::{
+    get_calories_sum, get_max_calories, get_report, get_stats, get_top_calories, parse_input,
+    ElfReport, Stats, StatsError, EXAMPLE,
+};
+
+// The histogram's longest bar is this wide, and the others are scaled to it
+const HISTOGRAM_WIDTH: usize = 40;
+const HISTOGRAM_BUCKETS: usize = 10;
+
+// Every elf by rank, with elves numbered from 1 like in the puzzle
+fn print_report(report: &[ElfReport]) {
+    println!(
+        "{:>5} {:>5} {:>6} {:>9} {:>9}",
+        "Rank", "Elf", "Items", "Calories", "Gap"
+    );
+    for elf in report {
+        let gap = elf
+            .gap
+            .as_ref()
+            .map_or(String::from("-"), |gap| gap.to_string());
+        println!(
+            "{:>5} {:>5} {:>6} {:>9} {:>9}",
+            elf.rank,
+            elf.index + 1,
+            elf.items,
+            elf.total,
+            gap
+        );
     }
-    f(curr_calories);
 }
 
-fn get_max_calories(input: &String) -> u32 {
-    let mut max_calories: u32 = 0;
+fn print_stats(stats: &Stats) {
+    println!("{:<16}{:>10}", "Elves", stats.elves);
+    println!("{:<16}{:>10}", "Min", stats.min);
+    println!("{:<16}{:>10}", "Max", stats.max);
+    println!("{:<16}{:>10.1}", "Mean", stats.mean);
+    println!("{:<16}{:>10.1}", "Median", stats.median);
+    println!("{:<16}{:>10.1}", "Std deviation", stats.standard_deviation);
+    for percent in [10, 25, 75, 90, 99] {
+        let label = format!("{}th percentile", percent);
+        println!("{:<16}{:>10.1}", label, stats.percentile(percent as f64));
+    }
 
-    iterate_input(input, |curr_calories| {
-        if curr_calories >= max_calories {
-            max_calories = curr_calories;
+    println!();
+    let histogram = stats.histogram(HISTOGRAM_BUCKETS);
+    let most = histogram.iter().map(|v| v.elves).max().unwrap_or(0).max(1);
+    for bucket in &histogram {
+        let mut bar = "#".repeat((bucket.elves * HISTOGRAM_WIDTH).div_ceil(most));
+        if !bar.is_empty() {
+            bar.push(' ');
         }
-    });
-    return max_calories;
-}
-
-fn get_top_calories(input: &String) -> [u32; 3] {
-    let mut top_calories: [u32; 4] = [0; 4];
-
-    iterate_input(input, |curr_calories| {
-        top_calories[3] = curr_calories;
-        top_calories.sort_by(|a, b| b.cmp(a));
-    });
-    let mut result = [0; 3];
-    for i in 0..3 {
-        result[i] = top_calories[i];
+        println!(
+            "{:>9.0} - {:>9.0} | {}{}",
+            bucket.start, bucket.end, bar, bucket.elves
+        );
     }
-
-    return result;
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
+fn main() {
+    log::init();
+    let (source, input_contents, flags) =
+        input::read_from_args_with(EXAMPLE, &["--report", "--stats"]);
 
-    #[test]
-    fn max_calories() {
-        let input_contents = fs::read_to_string("./test.txt").expect("Expected test file");
+    let inventories = parse_input(&input_contents).unwrap_or_else(|err| {
+        eprintln!("Invalid {} at {}", source, err);
+        process::exit(1);
+    });
 
-        let max_calories = get_max_calories(&input_contents);
-        assert_eq!(max_calories, 24000);
+    if flags.contains(&"--report") {
+        print_report(&get_report(&inventories));
+        return;
     }
-    #[test]
-    fn top_calories() {
-        let input_contents = fs::read_to_string("./test.txt").expect("Expected test file");
-
-        let top_calories = get_top_calories(&input_contents);
-
-        let top_calories_sum: u32 = top_calories.iter().sum();
-        assert_eq!(top_calories_sum, 45000);
+    if flags.contains(&"--stats") {
+        let stats = get_stats(&inventories).unwrap_or_else(|err| {
+            match err {
+                StatsError::NoElves => eprintln!("No elves in {}", source),
+                StatsError::TooLarge => eprintln!("Could not count {}: {}", source, err),
+            }
+            process::exit(1);
+        });
+        print_stats(&stats);
+        return;
     }
-}
-
-fn main() {
-    let input_contents =
-        fs::read_to_string("./input.txt").expect("Expected input file at input.txt");
 
-    let max_calories = get_max_calories(&input_contents);
-    let top_calories = get_top_calories(&input_contents);
+    let max_calories = get_max_calories(&inventories);
+    let top_calories = get_top_calories(&inventories);
 
-    let top_calories_sum: u32 = top_calories.iter().sum();
+    let top_calories_sum = get_calories_sum(&top_calories);
 
     println!("Max calories (part 1): {}", max_calories);
     println!("Top calories sum (part 2): {}", top_calories_sum);
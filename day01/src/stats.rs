@@ -0,0 +1,166 @@
+use crate::{calories, Calories, Inventory};
+use std::{error, fmt};
+
+/// How the calories carried by each elf are spread out
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    /// How many elves there are
+    pub elves: usize,
+    /// The fewest calories carried by an elf
+    pub min: Calories,
+    /// The most calories carried by an elf
+    pub max: Calories,
+    /// Average calories per elf
+    pub mean: f64,
+    /// Calories carried by the middle elf, or halfway between the two middle
+    /// ones
+    pub median: f64,
+    /// Population standard deviation of the calories per elf
+    pub standard_deviation: f64,
+    // Every elf's total, lowest first
+    totals: Vec<f64>,
+}
+
+/// A range of totals in a histogram, and how many elves carry that much
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bucket {
+    /// Lowest total in the range
+    pub start: f64,
+    /// Highest total in the range. Only the last range includes it
+    pub end: f64,
+    /// Elves whose total falls in the range
+    pub elves: usize,
+}
+
+/// Why there are no statistics on some inventories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsError {
+    /// There are no elves
+    NoElves,
+    /// An elf's total is too large for a float, which only happens with the
+    /// bignum feature
+    TooLarge,
+}
+
+impl fmt::Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            StatsError::NoElves => write!(f, "No elves"),
+            StatsError::TooLarge => write!(f, "Totals too large to count as floats"),
+        };
+    }
+}
+
+impl error::Error for StatsError {}
+
+/// Statistics on the total calories carried by each elf
+pub fn get_stats(inventories: &[Inventory]) -> Result<Stats, StatsError> {
+    let mut totals: Vec<&Calories> = inventories.iter().map(Inventory::total).collect();
+    totals.sort();
+
+    let min = Calories::to_owned(totals.first().ok_or(StatsError::NoElves)?);
+    let max = Calories::to_owned(totals.last().ok_or(StatsError::NoElves)?);
+    let totals: Vec<f64> = totals.into_iter().map(calories::to_f64).collect();
+    if !totals[totals.len() - 1].is_finite() {
+        return Err(StatsError::TooLarge);
+    }
+
+    // Totals that fit can still add up past the largest float, so they're
+    // scaled down first. By a power of two, which doesn't round them
+    let scale = match totals[totals.len() - 1] {
+        highest if highest > 0.0 => 2f64.powi(highest.log2().floor() as i32),
+        _ => 1.0,
+    };
+    let elves = totals.len();
+    let mean = totals.iter().map(|v| v / scale).sum::<f64>() / elves as f64;
+    let variance = totals
+        .iter()
+        .map(|v| (v / scale - mean).powi(2))
+        .sum::<f64>()
+        / elves as f64;
+
+    let mut stats = Stats {
+        elves,
+        min,
+        max,
+        mean: mean * scale,
+        median: 0.0,
+        standard_deviation: variance.sqrt() * scale,
+        totals,
+    };
+    stats.median = stats.percentile(50.0);
+    return Ok(stats);
+}
+
+impl Stats {
+    /// The total below which percent of the elves fall, interpolating
+    /// between the two closest elves. Clamped between 0 and 100
+    pub fn percentile(&self, percent: f64) -> f64 {
+        let rank = percent.clamp(0.0, 100.0) / 100.0 * (self.elves - 1) as f64;
+        let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+        let (low_total, high_total) = (self.totals[low], self.totals[high]);
+        return low_total + (high_total - low_total) * (rank - low as f64);
+    }
+
+    /// Elves counted in ranges of the same size from the lowest total to the
+    /// highest. A single range if every elf carries as much
+    pub fn histogram(&self, buckets: usize) -> Vec<Bucket> {
+        let (start, end) = (self.totals[0], self.totals[self.elves - 1]);
+        let buckets = if start == end { 1 } else { buckets.max(1) };
+        let width = (end - start) / buckets as f64;
+
+        let mut histogram: Vec<Bucket> = (0..buckets)
+            .map(|i| Bucket {
+                start: start + width * i as f64,
+                end: start + width * (i + 1) as f64,
+                elves: 0,
+            })
+            .collect();
+        for total in &self.totals {
+            let i = match width > 0.0 {
+                true => ((total - start) / width) as usize,
+                false => 0,
+            };
+            histogram[i.min(buckets - 1)].elves += 1;
+        }
+        return histogram;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, EXAMPLE};
+
+    #[test]
+    fn example_stats() {
+        let stats = get_stats(&parse_input(EXAMPLE).unwrap()).unwrap();
+
+        assert_eq!(stats.elves, 5);
+        assert_eq!(stats.min, Calories::from(4000u32));
+        assert_eq!(stats.max, Calories::from(24000u32));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        assert_eq!(stats.standard_deviation.round(), 6986.0);
+        assert_eq!(stats.percentile(25.0), 6000.0);
+        assert_eq!(stats.percentile(90.0), 18800.0);
+        assert_eq!(stats.percentile(100.0), 24000.0);
+
+        let elves: Vec<usize> = stats.histogram(4).iter().map(|v| v.elves).collect();
+        assert_eq!(elves, vec![2, 2, 0, 1]);
+        assert_eq!(get_stats(&[]), Err(StatsError::NoElves));
+    }
+
+    #[test]
+    #[cfg(feature = "bignum")]
+    fn huge_stats() {
+        // Each total fits in a float, but not their sum
+        let total = format!("1{}", "0".repeat(308));
+        let inventories = parse_input(&format!("{}\n\n{}\n", total, total)).unwrap();
+        let stats = get_stats(&inventories).unwrap();
+        assert_eq!((stats.mean, stats.standard_deviation), (1e308, 0.0));
+
+        let total = format!("1{}", "0".repeat(309));
+        let inventories = parse_input(&format!("1\n\n{}\n", total)).unwrap();
+        assert_eq!(get_stats(&inventories), Err(StatsError::TooLarge));
+    }
+}
@@ -0,0 +1,24 @@
+use common::solution_on_example;
+use day01::*;
+
+solution_on_example!(Day01, 24000, 45000);
+
+#[test]
+fn top_elves_from_outside() {
+    let inventories = day01::parse_input("1000\n2000\n\n4000\n\n5000\n6000\n").unwrap();
+
+    assert_eq!(get_max_calories(&inventories), Calories::from(11000u32));
+    assert_eq!(
+        get_top_calories(&inventories),
+        [11000u32, 4000, 3000].map(Calories::from)
+    );
+}
+
+#[test]
+fn inventories_by_hand() {
+    let items = [3000u32, 4000].map(Calories::from).to_vec();
+    let inventories = vec![Inventory::new(items).unwrap()];
+
+    assert_eq!(inventories[0].total(), &Calories::from(7000u32));
+    assert_eq!(get_max_calories(&inventories), Calories::from(7000u32));
+}
@@ -0,0 +1,28 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+common::snapshot_example_output!(env!("CARGO_BIN_EXE_day01"));
+common::snapshot_example_output!(example_report, env!("CARGO_BIN_EXE_day01"), ["--report"]);
+common::snapshot_example_output!(example_stats, env!("CARGO_BIN_EXE_day01"), ["--stats"]);
+
+// Blank lines alone make no elves, so there are no stats to show
+#[test]
+fn stats_without_elves() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day01"))
+        .args(["-", "--stats"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"\n\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "No elves in stdin\n"
+    );
+}
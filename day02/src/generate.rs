@@ -0,0 +1,54 @@
+use crate::Day02;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// The size is the number of rounds in the strategy guide. With the puzzle's
+// rules, shapes are numbered from 0 for rock, and each beats the one before
+// it, so both scores of a round follow from the two letters
+impl Generator for Day02 {
+    const INPUT_SIZE: usize = 2500;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut guide = String::new();
+        let (mut score_one, mut score_two) = (0u64, 0u64);
+
+        for _ in 0..size.max(1) {
+            let opponent: u8 = rng.gen_range(0..3);
+            let column: u8 = rng.gen_range(0..3);
+            guide.push_str(&format!(
+                "{} {}\n",
+                (b'A' + opponent) as char,
+                (b'X' + column) as char
+            ));
+
+            // As a shape, 0 is a draw, 1 a win and 2 a loss
+            let outcome = (column + 3 - opponent) % 3;
+            score_one += (column + 1 + (outcome + 1) % 3 * 3) as u64;
+            // As an end, X loses, Y draws and Z wins
+            let response = (opponent + column + 2) % 3;
+            score_two += (response + 1 + column * 3) as u64;
+        }
+        return (guide, (Answer::from(score_one), Answer::from(score_two)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day02::generate(7, 100);
+        assert_eq!(input, Day02::generate(7, 100).0);
+
+        let guide = Day02::parse(&input).unwrap();
+        assert_eq!(guide.len(), 100);
+
+        let (one, two) = answers;
+        assert_eq!(Day02::part_one(&guide), Ok(one));
+        assert_eq!(Day02::part_two(&guide), Ok(two));
+    }
+}
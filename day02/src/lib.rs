@@ -0,0 +1,358 @@
+//! Day 2: Rock Paper Scissors
+//!
+//! Scores a strategy guide for a rock paper scissors tournament, reading its
+//! second column either as a choice or as how each round has to end.
+#![warn(missing_docs)]
+
+use common::{stream, Answer, ParseError, Solution, SolveError, StreamError, Streaming};
+use std::{error, fmt, io::BufRead};
+use tracing::debug_span;
+
+#[cfg(feature = "generate")]
+mod generate;
+mod rules;
+
+use rules::{classic_rules, list_letters, GameState};
+pub use rules::{
+    RulesError, RulesErrorKind, Ruleset, Shape, CLASSIC_RULES, MAX_SCORE, RPSLS_RULES,
+};
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day02;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Why a strategy guide can't be scored when its second column is how each
+/// round has to end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreErrorKind {
+    /// The second column isn't one of the rules' letters for losing,
+    /// drawing and winning, given here. Those are X, Y and Z unless the
+    /// rules say otherwise, so rules with more shapes have letters that
+    /// only play a shape
+    NoEnd([char; 3]),
+    /// No shape of the rules ends the round that way
+    NoChoice,
+}
+
+/// A round of a strategy guide that couldn't be scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreError {
+    /// Round number, starting at 1
+    pub round: usize,
+    /// What was wrong with it
+    pub kind: ScoreErrorKind,
+}
+
+impl fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ScoreErrorKind::NoEnd(letters) => write!(
+                f,
+                "Expected round {} to say how to end, with {}",
+                self.round,
+                list_letters(letters.into_iter())
+            ),
+            ScoreErrorKind::NoChoice => write!(
+                f,
+                "Expected a shape to end round {} the way it says",
+                self.round
+            ),
+        }
+    }
+}
+
+impl error::Error for ScoreError {}
+
+impl From<ScoreError> for SolveError {
+    fn from(err: ScoreError) -> Self {
+        return SolveError::new(err.to_string());
+    }
+}
+
+// The score of a round when the second column is how it has to end. Rounds
+// are numbered from 1
+fn calculate_choice_and_score(
+    rules: &Ruleset,
+    round: &Round,
+    number: usize,
+) -> Result<u32, ScoreError> {
+    let error = |kind| ScoreError {
+        round: number,
+        kind,
+    };
+    let how_to_end = round
+        .end_choice
+        .ok_or(error(ScoreErrorKind::NoEnd(rules.end_letters())))?;
+    let my_choice = rules
+        .pick_choice(how_to_end, round.opp_choice)
+        .ok_or(error(ScoreErrorKind::NoChoice))?;
+    return Ok(rules.calculate_score(my_choice, round.opp_choice));
+}
+
+// A round of the strategy guide, as shapes of the rules it was read with. The
+// second column is read as a choice for part one, and as how the round needs
+// to end for part two, when it's one of the rules' letters for that
+#[derive(Debug, Clone)]
+struct Round {
+    opp_choice: usize,
+    my_choice: usize,
+    end_choice: Option<GameState>,
+}
+
+// Read the rounds line by line, calling f with each one. Choices are read as
+// the shapes of the rules
+fn iterate_input<L: AsRef<str>, E: From<ParseError>>(
+    rules: &Ruleset,
+    lines: impl Iterator<Item = Result<L, E>>,
+    mut f: impl FnMut(Round),
+) -> Result<(), E> {
+    let shapes = rules.shapes();
+    let opponent_expected = format!(
+        "Expected the opponent's choice, {}",
+        list_letters(shapes.iter().map(|shape| shape.opponent))
+    );
+    let response_expected = format!(
+        "Expected the second column, {}",
+        list_letters(shapes.iter().map(|shape| shape.response))
+    );
+
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+        let mut my_choice: Option<usize> = None;
+        let mut opp_choice: Option<usize> = None;
+
+        for (j, c) in line.char_indices() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let error = |message: &str| ParseError::at(i + 1, line, &line[j..], message);
+
+            if opp_choice.is_none() {
+                opp_choice = rules.opponent_shape(c);
+                if opp_choice.is_none() {
+                    return Err(error(&opponent_expected).into());
+                }
+            } else if my_choice.is_none() {
+                my_choice = rules.response_shape(c);
+                if my_choice.is_none() {
+                    return Err(error(&response_expected).into());
+                }
+            } else {
+                return Err(error("Expected the end of the round").into());
+            }
+        }
+
+        match (opp_choice, my_choice) {
+            (None, _) => continue,
+            (Some(opp_choice), Some(my_choice)) => f(Round {
+                opp_choice,
+                my_choice,
+                end_choice: rules.end_state(rules.shapes()[my_choice].response),
+            }),
+            _ => {
+                let column = line.chars().count() + 1;
+                return Err(ParseError::new(i + 1, column, line, response_expected).into());
+            }
+        }
+    }
+    return Ok(());
+}
+
+/// A strategy guide, along with the rules it was read with and is scored
+/// with
+#[derive(Debug, Clone)]
+pub struct Guide {
+    rules: Ruleset,
+    rounds: Vec<Round>,
+}
+
+impl Guide {
+    /// The rules the guide was read with
+    pub fn rules(&self) -> &Ruleset {
+        return &self.rules;
+    }
+
+    /// How many rounds the guide has
+    pub fn len(&self) -> usize {
+        return self.rounds.len();
+    }
+
+    /// Whether the guide has no rounds at all
+    pub fn is_empty(&self) -> bool {
+        return self.rounds.is_empty();
+    }
+}
+
+/// Read the opponent's choice and the second column of every round
+pub fn parse_input(input: &str) -> Result<Guide, ParseError> {
+    return parse_input_with(classic_rules(), input);
+}
+
+/// Same as [`parse_input`], reading choices as the shapes of other rules
+pub fn parse_input_with(rules: &Ruleset, input: &str) -> Result<Guide, ParseError> {
+    let mut rounds = Vec::new();
+    iterate_input(rules, input.lines().map(Ok), |round| rounds.push(round))?;
+    return Ok(Guide {
+        rules: rules.clone(),
+        rounds,
+    });
+}
+
+/// Total score when the second column is the choice to play
+pub fn get_score(guide: &Guide) -> u64 {
+    let mut score: u64 = 0;
+
+    // Scores are small enough for the total never to overflow
+    for (i, round) in guide.rounds.iter().enumerate() {
+        let _round = debug_span!("round", number = i + 1).entered();
+        score += u64::from(
+            guide
+                .rules
+                .calculate_score(round.my_choice, round.opp_choice),
+        );
+    }
+    return score;
+}
+
+/// Total score when the second column is how the round has to end. Fails on
+/// the first round that doesn't say how to end, or can't end that way with
+/// the guide's rules
+pub fn get_score_choice(guide: &Guide) -> Result<u64, ScoreError> {
+    let mut score: u64 = 0;
+
+    for (i, round) in guide.rounds.iter().enumerate() {
+        let _round = debug_span!("round", number = i + 1).entered();
+        score += u64::from(calculate_choice_and_score(&guide.rules, round, i + 1)?);
+    }
+    return Ok(score);
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Guide;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_score(input).into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_score_choice(input)?.into());
+    }
+}
+
+impl Streaming for Day02 {
+    fn solve_stream(reader: impl BufRead) -> Result<(Answer, Answer), StreamError> {
+        let rules = classic_rules();
+        let mut score: u64 = 0;
+        let mut score_choice: Result<u64, ScoreError> = Ok(0);
+        let mut number = 0;
+
+        iterate_input(rules, stream::lines(reader), |round| {
+            number += 1;
+            score += u64::from(rules.calculate_score(round.my_choice, round.opp_choice));
+            if let Ok(total) = score_choice {
+                score_choice = calculate_choice_and_score(rules, &round, number)
+                    .map(|round_score| total + u64::from(round_score));
+            }
+        })?;
+
+        let score_choice = score_choice.map_err(SolveError::from)?;
+        return Ok((score.into(), score_choice.into()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let guide = parse_input(EXAMPLE).unwrap();
+        let score = get_score(&guide);
+        assert_eq!(score, 15);
+    }
+    #[test]
+    fn part_two() {
+        let guide = parse_input(EXAMPLE).unwrap();
+        let score = get_score_choice(&guide);
+        assert_eq!(score, Ok(12));
+    }
+    #[test]
+    fn invalid_round() {
+        let error = parse_input("A Y\nB W\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = parse_input("A Y\nC\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let rules = Ruleset::from_toml(RPSLS_RULES).unwrap();
+        let guide = parse_input_with(&rules, "A Y\nB X\nC Z\nD W\nE V\n").unwrap();
+        assert_eq!(get_score(&guide), 8 + 1 + 6 + 5 + 10);
+
+        let guide = parse_input_with(&rules, "A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(get_score_choice(&guide), Ok(4 + 1 + 7));
+
+        let error = parse_input_with(&rules, "F X\n").unwrap_err();
+        assert_eq!(
+            error.message,
+            "Expected the opponent's choice, A, B, C, D or E"
+        );
+    }
+    #[test]
+    fn guides_keep_their_rules() {
+        let rules = Ruleset::from_toml(RPSLS_RULES).unwrap();
+        let guide = parse_input_with(&rules, "E W\n").unwrap();
+
+        // Spock against Spock, scored with the rules that know Spock
+        assert_eq!(get_score(&guide), 5 + 3);
+        assert_eq!(guide.rules(), &rules);
+        assert_eq!(
+            get_score_choice(&guide),
+            Err(ScoreError {
+                round: 1,
+                kind: ScoreErrorKind::NoEnd(['X', 'Y', 'Z']),
+            })
+        );
+        assert_eq!(
+            Day02::part_two(&guide),
+            Err(SolveError::new(
+                "Expected round 1 to say how to end, with X, Y or Z"
+            ))
+        );
+
+        // The puzzle's rules don't know Spock
+        let error = parse_input("E W\n").unwrap_err();
+        assert_eq!(error.message, "Expected the opponent's choice, A, B or C");
+    }
+    #[test]
+    fn rpsls_guide_ending_rounds() {
+        // Lizard and Spock's letters only play them, unless the rules pick
+        // them to end rounds with
+        let rules = Ruleset::from_toml(RPSLS_RULES).unwrap();
+        let guide = parse_input_with(&rules, "A X\nD V\n").unwrap();
+        assert_eq!(get_score(&guide), 4 + 7);
+        assert_eq!(
+            get_score_choice(&guide).unwrap_err().to_string(),
+            "Expected round 2 to say how to end, with X, Y or Z"
+        );
+
+        let rules = Ruleset::from_toml(&format!(
+            "[ends]\nlose = \"V\"\ndraw = \"W\"\nwin = \"X\"\n{}",
+            RPSLS_RULES
+        ))
+        .unwrap();
+        let guide = parse_input_with(&rules, "A X\nD V\nE W\n").unwrap();
+        // Paper beats Rock, Paper loses to Lizard, Spock draws
+        assert_eq!(get_score_choice(&guide), Ok(8 + 2 + 8));
+    }
+    common::stream_matches_parse!(Day02);
+}
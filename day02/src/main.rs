@@ -1,176 +1,39 @@
-use std::fs;
+use common::{input, log};
+use std::{fs, process};
 
-#[derive(PartialEq, Clone)]
-enum Choice {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-enum EndChoice {
-    Lose,
-    Draw,
-    Win,
-}
-
-enum GameState {
-    Lose,
-    Draw,
-    Win,
-}
-
-fn char_to_choice(str: char) -> Option<Choice> {
-    match str {
-        'A' | 'X' => Some(Choice::Rock),
-        'B' | 'Y' => Some(Choice::Paper),
-        'C' | 'Z' => Some(Choice::Scissors),
-        _ => None,
-    }
-}
-fn char_to_end_choice(str: char) -> Option<EndChoice> {
-    match str {
-        'X' => Some(EndChoice::Lose),
-        'Y' => Some(EndChoice::Draw),
-        'Z' => Some(EndChoice::Win),
-        _ => None,
-    }
-}
-
-fn calculate_score(my_choice: &Choice, opp_choice: &Choice) -> u32 {
-    let mut score: u32 = 0;
+use day02::{get_score, get_score_choice, parse_input_with, Ruleset, EXAMPLE};
 
-    score += match my_choice {
-        Choice::Rock => 1,
-        Choice::Paper => 2,
-        Choice::Scissors => 3,
+// The puzzle's rules, or the ones in the TOML file given with --rules
+fn read_rules(path: Option<&str>) -> Ruleset {
+    let Some(path) = path else {
+        return Ruleset::classic();
     };
-
-    let mut game_state: GameState;
-
-    game_state = match (my_choice, opp_choice) {
-        (Choice::Rock, Choice::Scissors) => GameState::Win,
-        (Choice::Paper, Choice::Rock) => GameState::Win,
-        (Choice::Scissors, Choice::Paper) => GameState::Win,
-        _ => GameState::Lose,
-    };
-    if my_choice == opp_choice {
-        game_state = GameState::Draw;
-    }
-
-    score += match game_state {
-        GameState::Draw => 3,
-        GameState::Win => 6,
-        GameState::Lose => 0,
-    };
-
-    return score;
-}
-
-fn get_winner_choice(choice: &Choice) -> Choice {
-    match choice {
-        Choice::Scissors => Choice::Rock,
-        Choice::Rock => Choice::Paper,
-        Choice::Paper => Choice::Scissors,
-    }
-}
-
-fn calculate_choice_and_score(how_to_end: &EndChoice, opp_choice: &Choice) -> u32 {
-    let my_choice: Choice;
-
-    match how_to_end {
-        EndChoice::Draw => {
-            my_choice = opp_choice.clone();
-        }
-        EndChoice::Lose => {
-            my_choice = get_winner_choice(&get_winner_choice(&opp_choice));
-        }
-        EndChoice::Win => {
-            my_choice = get_winner_choice(&opp_choice);
-        }
-    }
-
-    return calculate_score(&my_choice, opp_choice);
-}
-
-fn get_score(input: &String) -> u32 {
-    let mut my_choice: Option<Choice> = None;
-    let mut opp_choice: Option<Choice> = None;
-
-    let mut score: u32 = 0;
-
-    for c in input.chars() {
-        if c == '\n' {
-            if my_choice.is_none() || opp_choice.is_none() {
-                continue;
-            }
-            score += calculate_score(&my_choice.unwrap(), &opp_choice.unwrap());
-            my_choice = None;
-            opp_choice = None;
-            continue;
-        }
-        if opp_choice.is_none() {
-            opp_choice = char_to_choice(c);
-        } else if my_choice.is_none() {
-            my_choice = char_to_choice(c);
-        }
-    }
-    return score;
-}
-fn get_score_choice(input: &String) -> u32 {
-    let mut my_choice: Option<Choice> = None;
-    let mut opp_choice: Option<Choice> = None;
-    let mut end_choice: Option<EndChoice> = None;
-
-    let mut score: u32 = 0;
-
-    for c in input.chars() {
-        if c == '\n' {
-            if my_choice.is_none() || opp_choice.is_none() {
-                continue;
-            }
-            score += calculate_choice_and_score(&end_choice.unwrap(), &opp_choice.unwrap());
-            my_choice = None;
-            opp_choice = None;
-            end_choice = None;
-            continue;
-        }
-        if opp_choice.is_none() {
-            opp_choice = char_to_choice(c);
-        } else if my_choice.is_none() {
-            end_choice = char_to_end_choice(c);
-            my_choice = char_to_choice(c);
-        }
-    }
-    return score;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-
-    #[test]
-    fn part_one() {
-        let input_contents = fs::read_to_string("./test.txt").expect("Expected test file");
-
-        let score = get_score(&input_contents);
-        assert_eq!(score, 15);
-    }
-    #[test]
-    fn part_two() {
-        let input_contents = fs::read_to_string("./test.txt").expect("Expected test file");
-
-        let score = get_score_choice(&input_contents);
-        assert_eq!(score, 12);
-    }
+    let contents = fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("Could not read rules from {}: {}", path, err);
+        process::exit(1);
+    });
+    return Ruleset::from_toml(&contents).unwrap_or_else(|err| {
+        eprintln!("Invalid rules in {}: {}", path, err);
+        process::exit(1);
+    });
 }
 
 fn main() {
-    let input_contents =
-        fs::read_to_string("./input.txt").expect("Expected input file at input.txt");
-
-    let score_first = get_score(&input_contents);
-    let score_second = get_score_choice(&input_contents);
+    log::init();
+    let (source, input_contents, _, options) =
+        input::read_from_args_with_options(EXAMPLE, &[], &["--rules"]);
+    let rules = read_rules(options.last().map(|(_, path)| path.as_str()));
+
+    let guide = parse_input_with(&rules, &input_contents).unwrap_or_else(|err| {
+        eprintln!("Invalid {} at {}", source, err);
+        process::exit(1);
+    });
+
+    let score_first = get_score(&guide);
+    let score_second = get_score_choice(&guide).unwrap_or_else(|err| {
+        eprintln!("Could not score {}: {}", source, err);
+        process::exit(1);
+    });
 
     println!("Score (part 1): {}", score_first);
     println!("Score (part 2): {}", score_second);
@@ -0,0 +1,519 @@
+use serde::Deserialize;
+use std::{error, fmt, sync::OnceLock};
+use tracing::debug;
+
+/// The rules from the puzzle, as a file to start other rules from
+pub const CLASSIC_RULES: &str = include_str!("../rules/classic.toml");
+
+/// Rock Paper Scissors Lizard Spock
+pub const RPSLS_RULES: &str = include_str!("../rules/rpsls.toml");
+
+/// The highest score rules can give for a shape or an outcome, so a guide's
+/// total always fits in 64 bits
+pub const MAX_SCORE: u32 = 1_000_000;
+
+// How a round ended for the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GameState {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// A shape that can be played, and how it's written in the strategy guide
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    /// What the shape is called
+    pub name: String,
+    /// The letter in the first column when the opponent plays it
+    pub opponent: char,
+    /// The letter in the second column when the player is to play it
+    pub response: char,
+    /// Score for playing it, whatever the outcome
+    pub score: u32,
+    // Indices of the shapes it beats
+    beats: Vec<usize>,
+}
+
+/// The shapes of a game like rock paper scissors, which ones beat which, how
+/// rounds are scored and how the guide says to end them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ruleset {
+    shapes: Vec<Shape>,
+    // Scores for losing, drawing and winning
+    outcomes: [u32; 3],
+    // Letters in the second column for losing, drawing and winning
+    ends: [char; 3],
+}
+
+/// What was wrong with some rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulesErrorKind {
+    /// The file isn't TOML, or isn't made of the tables rules are, with the
+    /// TOML parser's message
+    Toml(String),
+    /// There are fewer than two shapes to play
+    TooFewShapes,
+    /// An earlier shape has the same name
+    SameName,
+    /// An earlier shape is played by the opponent as the same letter
+    SameOpponent(char),
+    /// An earlier shape is played in response as the same letter
+    SameResponse(char),
+    /// The shape scores more than [`MAX_SCORE`]
+    ShapeScore,
+    /// One of the shape's letters is whitespace, which can't be told apart
+    /// from the space between columns
+    WhitespaceLetter,
+    /// The shape beats a shape with a name no shape has
+    UnknownShape(String),
+    /// The shape beats itself
+    BeatsItself,
+    /// The shape and the named one beat each other
+    BeatEachOther(String),
+    /// A loss, draw or win, as named, scores more than [`MAX_SCORE`]
+    OutcomeScore(&'static str),
+    /// The letter for how to end a round isn't any shape's response letter,
+    /// so it couldn't be read from a guide
+    UnknownEnd(char),
+    /// Two ways to end a round have the same letter
+    SameEnd(char),
+}
+
+/// Rules that don't make a game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RulesError {
+    /// Name of the shape that's wrong, if it's about one
+    pub shape: Option<String>,
+    /// What was wrong
+    pub kind: RulesErrorKind,
+}
+
+impl RulesError {
+    fn new(kind: RulesErrorKind) -> Self {
+        return RulesError { shape: None, kind };
+    }
+
+    fn of(shape: &str, kind: RulesErrorKind) -> Self {
+        return RulesError {
+            shape: Some(shape.to_string()),
+            kind,
+        };
+    }
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let shape = self.shape.as_deref().unwrap_or_default();
+        match &self.kind {
+            RulesErrorKind::Toml(message) => write!(f, "{}", message),
+            RulesErrorKind::TooFewShapes => write!(f, "Expected at least two shapes"),
+            RulesErrorKind::SameName => write!(f, "Expected a single shape named '{}'", shape),
+            RulesErrorKind::SameOpponent(letter) => write!(
+                f,
+                "Expected a single shape played by the opponent as '{}'",
+                letter
+            ),
+            RulesErrorKind::SameResponse(letter) => write!(
+                f,
+                "Expected a single shape played in response as '{}'",
+                letter
+            ),
+            RulesErrorKind::ShapeScore => write!(
+                f,
+                "Expected the score of '{}' to be at most {}",
+                shape, MAX_SCORE
+            ),
+            RulesErrorKind::WhitespaceLetter => write!(
+                f,
+                "Expected the letters of '{}' not to be whitespace",
+                shape
+            ),
+            RulesErrorKind::UnknownShape(name) => {
+                write!(f, "Expected '{}' to beat a shape, not '{}'", shape, name)
+            }
+            RulesErrorKind::BeatsItself => write!(f, "Expected '{}' not to beat itself", shape),
+            RulesErrorKind::BeatEachOther(other) => write!(
+                f,
+                "Expected '{}' and '{}' not to beat each other",
+                shape, other
+            ),
+            RulesErrorKind::OutcomeScore(outcome) => write!(
+                f,
+                "Expected the score of a {} to be at most {}",
+                outcome, MAX_SCORE
+            ),
+            RulesErrorKind::UnknownEnd(letter) => write!(
+                f,
+                "Expected '{}', a way to end rounds, to be a shape's response letter",
+                letter
+            ),
+            RulesErrorKind::SameEnd(letter) => write!(
+                f,
+                "Expected a single way to end rounds written as '{}'",
+                letter
+            ),
+        }
+    }
+}
+
+impl error::Error for RulesError {}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    #[serde(default)]
+    outcomes: OutcomesFile,
+    #[serde(default)]
+    ends: EndsFile,
+    shapes: Vec<ShapeFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomesFile {
+    lose: u32,
+    draw: u32,
+    win: u32,
+}
+
+// Outcomes score as in the puzzle unless the rules say otherwise
+impl Default for OutcomesFile {
+    fn default() -> Self {
+        return OutcomesFile {
+            lose: 0,
+            draw: 3,
+            win: 6,
+        };
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EndsFile {
+    lose: char,
+    draw: char,
+    win: char,
+}
+
+// The guide says how to end rounds as in the puzzle unless the rules say
+// otherwise
+impl Default for EndsFile {
+    fn default() -> Self {
+        return EndsFile {
+            lose: 'X',
+            draw: 'Y',
+            win: 'Z',
+        };
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ShapeFile {
+    name: String,
+    opponent: char,
+    response: char,
+    score: u32,
+    #[serde(default)]
+    beats: Vec<String>,
+}
+
+// The letters of some shapes, as "A, B or C"
+pub(crate) fn list_letters(letters: impl Iterator<Item = char>) -> String {
+    let letters: Vec<String> = letters.map(String::from).collect();
+    return match letters.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    };
+}
+
+impl Ruleset {
+    /// Read rules from a TOML file: a `[[shapes]]` table for each shape, with
+    /// its name, letters, score and the names of the shapes it beats, an
+    /// optional `[outcomes]` table scoring a loss, a draw and a win, and an
+    /// optional `[ends]` table with the second column's letters for losing,
+    /// drawing and winning, X, Y and Z otherwise. Fails on anything that
+    /// doesn't make a game, like a shape beating itself, on scores over
+    /// [`MAX_SCORE`] and on letters to end rounds with that no shape has
+    pub fn from_toml(contents: &str) -> Result<Ruleset, RulesError> {
+        let file: RulesFile = toml::from_str(contents)
+            .map_err(|err| RulesError::new(RulesErrorKind::Toml(err.message().to_string())))?;
+
+        if file.shapes.len() < 2 {
+            return Err(RulesError::new(RulesErrorKind::TooFewShapes));
+        }
+        for (i, shape) in file.shapes.iter().enumerate() {
+            let others = &file.shapes[..i];
+            let error = |kind| Err(RulesError::of(&shape.name, kind));
+            if others.iter().any(|other| other.name == shape.name) {
+                return error(RulesErrorKind::SameName);
+            }
+            if others.iter().any(|other| other.opponent == shape.opponent) {
+                return error(RulesErrorKind::SameOpponent(shape.opponent));
+            }
+            if others.iter().any(|other| other.response == shape.response) {
+                return error(RulesErrorKind::SameResponse(shape.response));
+            }
+            if shape.score > MAX_SCORE {
+                return error(RulesErrorKind::ShapeScore);
+            }
+            if shape.opponent.is_whitespace() || shape.response.is_whitespace() {
+                return error(RulesErrorKind::WhitespaceLetter);
+            }
+        }
+
+        let mut shapes = Vec::with_capacity(file.shapes.len());
+        for shape in &file.shapes {
+            let mut beats = Vec::with_capacity(shape.beats.len());
+            for name in &shape.beats {
+                let index = file
+                    .shapes
+                    .iter()
+                    .position(|other| other.name == *name)
+                    .ok_or_else(|| {
+                        RulesError::of(&shape.name, RulesErrorKind::UnknownShape(name.clone()))
+                    })?;
+                if *name == shape.name {
+                    return Err(RulesError::of(&shape.name, RulesErrorKind::BeatsItself));
+                }
+                beats.push(index);
+            }
+            shapes.push(Shape {
+                name: shape.name.clone(),
+                opponent: shape.opponent,
+                response: shape.response,
+                score: shape.score,
+                beats,
+            });
+        }
+
+        for (i, shape) in shapes.iter().enumerate() {
+            if let Some(other) = shape
+                .beats
+                .iter()
+                .find(|other| shapes[**other].beats.contains(&i))
+            {
+                let other = shapes[*other].name.clone();
+                return Err(RulesError::of(
+                    &shape.name,
+                    RulesErrorKind::BeatEachOther(other),
+                ));
+            }
+        }
+
+        let outcomes = [file.outcomes.lose, file.outcomes.draw, file.outcomes.win];
+        for (name, score) in ["loss", "draw", "win"].into_iter().zip(outcomes) {
+            if score > MAX_SCORE {
+                return Err(RulesError::new(RulesErrorKind::OutcomeScore(name)));
+            }
+        }
+
+        // Both parts read the same guide, so ending rounds is written with
+        // the shapes' response letters
+        let ends = [file.ends.lose, file.ends.draw, file.ends.win];
+        for (i, letter) in ends.into_iter().enumerate() {
+            if !shapes.iter().any(|shape| shape.response == letter) {
+                return Err(RulesError::new(RulesErrorKind::UnknownEnd(letter)));
+            }
+            if ends[..i].contains(&letter) {
+                return Err(RulesError::new(RulesErrorKind::SameEnd(letter)));
+            }
+        }
+        return Ok(Ruleset {
+            shapes,
+            outcomes,
+            ends,
+        });
+    }
+
+    /// The rules from the puzzle: rock, paper and scissors
+    pub fn classic() -> Ruleset {
+        return classic_rules().clone();
+    }
+
+    /// Every shape, in the order the rules list them
+    pub fn shapes(&self) -> &[Shape] {
+        return &self.shapes;
+    }
+
+    // The shape the opponent plays as a letter
+    pub(crate) fn opponent_shape(&self, letter: char) -> Option<usize> {
+        return self
+            .shapes
+            .iter()
+            .position(|shape| shape.opponent == letter);
+    }
+
+    /// The second column's letters for losing, drawing and winning a round
+    pub fn end_letters(&self) -> [char; 3] {
+        return self.ends;
+    }
+
+    // How the round has to end, as a letter of the second column
+    pub(crate) fn end_state(&self, letter: char) -> Option<GameState> {
+        let states = [GameState::Lose, GameState::Draw, GameState::Win];
+        let index = self.ends.iter().position(|end| *end == letter)?;
+        return Some(states[index]);
+    }
+
+    // The shape the player is to play as a letter
+    pub(crate) fn response_shape(&self, letter: char) -> Option<usize> {
+        return self
+            .shapes
+            .iter()
+            .position(|shape| shape.response == letter);
+    }
+
+    fn game_state(&self, my_choice: usize, opp_choice: usize) -> GameState {
+        if self.shapes[my_choice].beats.contains(&opp_choice) {
+            return GameState::Win;
+        }
+        if self.shapes[opp_choice].beats.contains(&my_choice) {
+            return GameState::Lose;
+        }
+        return GameState::Draw;
+    }
+
+    pub(crate) fn calculate_score(&self, my_choice: usize, opp_choice: usize) -> u32 {
+        let shape = self.shapes[my_choice].score;
+        let outcome = match self.game_state(my_choice, opp_choice) {
+            GameState::Lose => self.outcomes[0],
+            GameState::Draw => self.outcomes[1],
+            GameState::Win => self.outcomes[2],
+        };
+
+        let score = shape + outcome;
+        debug!(
+            me = self.shapes[my_choice].name,
+            opponent = self.shapes[opp_choice].name,
+            shape,
+            outcome,
+            score,
+            "round's score"
+        );
+        return score;
+    }
+
+    // The first shape, in the order of the rules, ending the round as wanted.
+    // None if no shape does, like when nothing beats the opponent's
+    pub(crate) fn pick_choice(&self, how_to_end: GameState, opp_choice: usize) -> Option<usize> {
+        let my_choice = match how_to_end {
+            GameState::Draw => Some(opp_choice),
+            _ => (0..self.shapes.len())
+                .find(|my_choice| self.game_state(*my_choice, opp_choice) == how_to_end),
+        };
+        debug!(
+            end = ?how_to_end,
+            me = my_choice.map(|v| self.shapes[v].name.as_str()),
+            "picked a choice"
+        );
+        return my_choice;
+    }
+}
+
+// Built once, as every round of the puzzle is scored with it
+pub(crate) fn classic_rules() -> &'static Ruleset {
+    static CLASSIC: OnceLock<Ruleset> = OnceLock::new();
+    return CLASSIC.get_or_init(|| Ruleset::from_toml(CLASSIC_RULES).unwrap());
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        return Ruleset::classic();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_rules() {
+        let rules = Ruleset::classic();
+        let names: Vec<&str> = rules.shapes().iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["Rock", "Paper", "Scissors"]);
+
+        assert_eq!(rules.calculate_score(1, 0), 8);
+        assert_eq!(rules.calculate_score(0, 1), 1);
+        assert_eq!(rules.calculate_score(2, 2), 6);
+        assert_eq!(rules.pick_choice(GameState::Lose, 0), Some(2));
+    }
+    #[test]
+    fn invalid_rules() {
+        let shape = |name: &str, letter: char, beats: &str| {
+            let response = (letter as u8 + 23) as char;
+            return format!(
+                "[[shapes]]\nname = \"{}\"\nopponent = \"{}\"\nresponse = \"{}\"\nscore = 1\nbeats = [{}]\n",
+                name, letter, response, beats
+            );
+        };
+        let error = |rules: &[String]| Ruleset::from_toml(&rules.concat()).unwrap_err();
+
+        assert_eq!(
+            error(&[shape("Rock", 'A', "")]).kind,
+            RulesErrorKind::TooFewShapes
+        );
+        let beats_itself = error(&[shape("Rock", 'A', "\"Rock\""), shape("Paper", 'B', "")]);
+        assert_eq!(beats_itself.kind, RulesErrorKind::BeatsItself);
+        assert_eq!(beats_itself.shape.as_deref(), Some("Rock"));
+        assert_eq!(
+            error(&[
+                shape("Rock", 'A', "\"Paper\""),
+                shape("Paper", 'B', "\"Rock\"")
+            ])
+            .kind,
+            RulesErrorKind::BeatEachOther(String::from("Paper"))
+        );
+        let unknown = error(&[shape("Rock", 'A', "\"Scissors\""), shape("Paper", 'B', "")]);
+        assert_eq!(
+            unknown.kind,
+            RulesErrorKind::UnknownShape(String::from("Scissors"))
+        );
+        assert_eq!(
+            unknown.to_string(),
+            "Expected 'Rock' to beat a shape, not 'Scissors'"
+        );
+        assert_eq!(
+            error(&[shape("Rock", 'A', ""), shape("Paper", 'A', "")]).kind,
+            RulesErrorKind::SameOpponent('A')
+        );
+        let outcome = error(&[
+            String::from("[outcomes]\nlose = 0\ndraw = 4294967295\nwin = 6\n"),
+            shape("Rock", 'A', "\"Paper\""),
+            shape("Paper", 'B', ""),
+        ]);
+        assert_eq!(outcome.kind, RulesErrorKind::OutcomeScore("draw"));
+        assert_eq!(
+            outcome.to_string(),
+            "Expected the score of a draw to be at most 1000000"
+        );
+        assert!(matches!(
+            Ruleset::from_toml("[[shapes]]\nname = \"Rock\"\n").map_err(|err| err.kind),
+            Err(RulesErrorKind::Toml(_))
+        ));
+
+        // Only Rock and Paper, played as X and Y, so Z ends nothing
+        let two_shapes = [shape("Rock", 'A', "\"Paper\""), shape("Paper", 'B', "")];
+        assert_eq!(error(&two_shapes).kind, RulesErrorKind::UnknownEnd('Z'));
+        let ends = String::from("[ends]\nlose = \"X\"\ndraw = \"Y\"\nwin = \"X\"\n");
+        let same_end = error(&[ends, two_shapes[0].clone(), two_shapes[1].clone()]);
+        assert_eq!(same_end.kind, RulesErrorKind::SameEnd('X'));
+    }
+    #[test]
+    fn end_letters() {
+        let rules = Ruleset::from_toml(RPSLS_RULES).unwrap();
+        assert_eq!(rules.end_letters(), ['X', 'Y', 'Z']);
+        assert_eq!(rules.end_state('V'), None);
+
+        let rules = Ruleset::from_toml(&format!(
+            "[ends]\nlose = \"V\"\ndraw = \"W\"\nwin = \"X\"\n{}",
+            RPSLS_RULES
+        ))
+        .unwrap();
+        assert_eq!(rules.end_state('V'), Some(GameState::Lose));
+        assert_eq!(rules.end_state('X'), Some(GameState::Win));
+        assert_eq!(rules.end_state('Z'), None);
+    }
+}
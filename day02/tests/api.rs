@@ -0,0 +1,26 @@
+use common::solution_on_example;
+use day02::*;
+
+solution_on_example!(Day02, 15, 12);
+
+#[test]
+fn strategy_guide_from_outside() {
+    let guide = day02::parse_input("A Y\nB X\nC Z\n").unwrap();
+
+    assert_eq!(guide.len(), 3);
+    assert_eq!(get_score(&guide), 15);
+    assert_eq!(get_score_choice(&guide), Ok(12));
+}
+
+#[test]
+fn rules_from_toml() {
+    let rules = Ruleset::from_toml(RPSLS_RULES).unwrap();
+    let guide = parse_input_with(&rules, "D X\nE Y\n").unwrap();
+
+    assert_eq!(guide.rules().shapes().len(), 5);
+    assert_eq!(get_score(&guide), 7 + 8);
+    assert_eq!(get_score_choice(&guide), Ok(2 + 8));
+
+    let classic = Ruleset::from_toml(CLASSIC_RULES).unwrap();
+    assert_eq!(classic, Ruleset::classic());
+}
@@ -0,0 +1,6 @@
+common::snapshot_example_output!(env!("CARGO_BIN_EXE_day02"));
+common::snapshot_example_output!(
+    rpsls_rules,
+    env!("CARGO_BIN_EXE_day02"),
+    ["--rules", "rules/rpsls.toml"]
+);
@@ -0,0 +1,76 @@
+use crate::Day03;
+use common::{Answer, Generated, Generator};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// Fill a compartment with the given items plus random ones from a pool
+fn fill_compartment(rng: &mut ChaCha8Rng, items: &[u8], pool: &[u8], length: usize) -> String {
+    let mut compartment = items.to_vec();
+    while compartment.len() < length {
+        compartment.push(*pool.choose(rng).unwrap());
+    }
+    compartment.shuffle(rng);
+    return String::from_utf8(compartment).unwrap();
+}
+
+// Lowercase items have priorities 1 to 26, uppercase ones 27 to 52
+fn priority(item: u8) -> u64 {
+    return ITEMS.iter().position(|v| *v == item).unwrap() as u64 + 1;
+}
+
+// The size is the number of rucksacks, rounded up to whole groups of three.
+// Each group has its own items, so the badge is the only one they share, and
+// each rucksack has a single item in both compartments. Those are the items
+// the answers add up
+impl Generator for Day03 {
+    const INPUT_SIZE: usize = 300;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut rucksacks = String::new();
+        let (mut shared_sum, mut badge_sum) = (0, 0);
+
+        for _ in 0..size.max(1).div_ceil(3) {
+            let mut items = ITEMS.to_vec();
+            items.shuffle(&mut rng);
+            let badge = items[0];
+            badge_sum += priority(badge);
+
+            for pool in items[1..].chunks(17) {
+                let shared = pool[0];
+                shared_sum += priority(shared);
+                let (first, second) = pool[1..].split_at(8);
+                let length = rng.gen_range(2..=12);
+
+                rucksacks.push_str(&fill_compartment(&mut rng, &[shared, badge], first, length));
+                rucksacks.push_str(&fill_compartment(&mut rng, &[shared], second, length));
+                rucksacks.push('\n');
+            }
+        }
+        return (
+            rucksacks,
+            (Answer::from(shared_sum), Answer::from(badge_sum)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day03::generate(7, 100);
+        assert_eq!(input, Day03::generate(7, 100).0);
+
+        let rucksacks = Day03::parse(&input).unwrap();
+        assert_eq!(rucksacks.len(), 102);
+
+        let (one, two) = answers;
+        assert_eq!(Day03::part_one(&rucksacks), Ok(one));
+        assert_eq!(Day03::part_two(&rucksacks), Ok(two));
+    }
+}
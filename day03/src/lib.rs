@@ -0,0 +1,329 @@
+//! Day 3: Rucksack Reorganization
+//!
+//! Finds the item type misplaced in both compartments of each rucksack, and the
+//! badge shared by every group of three elves, and adds up their priorities.
+#![warn(missing_docs)]
+
+use common::{stream, Answer, ParseError, Solution, SolveError, StreamError, Streaming};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::io::BufRead;
+use tracing::debug;
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day03;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+static LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+fn get_letter_priority(letter: char) -> u64 {
+    let is_uppercase = letter.is_uppercase();
+
+    let mut priority = LETTERS.find(letter.to_ascii_lowercase()).unwrap_or(0);
+    priority += if is_uppercase { LETTERS.len() } else { 0 };
+    return priority as u64 + 1;
+}
+
+fn iterate_for_both(compartment1: &mut String, compartment2: String) -> Option<char> {
+    let c1 = compartment1.chars().next()?;
+    for c2 in compartment2.chars() {
+        if c2 == c1 {
+            return Some(c1);
+        }
+    }
+
+    let com = compartment1.replace(c1, "");
+    compartment1.clear();
+    compartment1.push_str(com.as_str());
+
+    return iterate_for_both(compartment1, compartment2);
+}
+
+fn iterate_for_badges(r1: String, r2: String, r3: String) -> Option<char> {
+    for c1 in r1.chars() {
+        for c2 in r2.chars() {
+            for c3 in r3.chars() {
+                if c1 == c2 && c2 == c3 {
+                    return Some(c1);
+                }
+            }
+        }
+    }
+    return None;
+}
+
+fn get_both_contains(rucksack: &str) -> Option<char> {
+    let splitted = rucksack.split_at(rucksack.len() / 2);
+
+    let mut compartment1 = splitted.0.to_string();
+    let compartment2 = splitted.1.to_string();
+
+    let found = iterate_for_both(&mut compartment1, compartment2);
+    debug!(rucksack, item = ?found, "in both compartments");
+
+    return found;
+}
+
+fn get_badge_contains(rucksack1: &str, rucksack2: &str, rucksack3: &str) -> Option<char> {
+    let r2 = rucksack2.to_string();
+    let r3 = rucksack3.to_string();
+    let badge = iterate_for_badges(rucksack1.to_string(), r2, r3);
+    debug!(badge = ?badge, "group's badge");
+    return badge;
+}
+
+// The priority of the item in both compartments of a rucksack, counting
+// rucksacks from 1. Fails if there's none
+fn get_rucksack_priority(number: usize, rucksack: &str) -> Result<u64, SolveError> {
+    let found = get_both_contains(rucksack).ok_or_else(|| {
+        SolveError::new(format!(
+            "Expected rucksack {} to have an item in both compartments",
+            number
+        ))
+    })?;
+    return Ok(get_letter_priority(found));
+}
+
+// The priority of the badge of a group, counting groups from 1. Fails if
+// there's none
+fn get_badge_priority(number: usize, group: &[impl AsRef<str>]) -> Result<u64, SolveError> {
+    let [r1, r2, r3] = group else {
+        unreachable!("Groups always have three elves");
+    };
+    let badge = get_badge_contains(r1.as_ref(), r2.as_ref(), r3.as_ref()).ok_or_else(|| {
+        SolveError::new(format!(
+            "Expected the elves of group {} to share a badge",
+            number
+        ))
+    })?;
+    return Ok(get_letter_priority(badge));
+}
+
+// Read the rucksacks line by line, calling f with each one
+fn iterate_input<L: AsRef<str>, E: From<ParseError>>(
+    lines: impl Iterator<Item = Result<L, E>>,
+    mut f: impl FnMut(&str),
+) -> Result<(), E> {
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some((j, _)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::at(
+                i + 1,
+                line,
+                &line[j..],
+                "Expected an item, from a to z or A to Z",
+            )
+            .into());
+        }
+        if line.len() % 2 != 0 {
+            return Err(ParseError::new(
+                i + 1,
+                line.len() + 1,
+                line,
+                "Expected both compartments to have the same number of items",
+            )
+            .into());
+        }
+        f(line);
+    }
+    return Ok(());
+}
+
+/// Read every rucksack, whose items are letters split evenly between its
+/// two compartments
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut rucksacks = Vec::new();
+    iterate_input(input.lines().map(Ok), |rucksack| {
+        rucksacks.push(rucksack.to_string())
+    })?;
+    return Ok(rucksacks);
+}
+
+/// Sum of the priorities of the item in both compartments of each rucksack.
+/// Fails on a rucksack without one
+pub fn get_sum_of_priorities(rucksacks: &[String]) -> Result<u64, SolveError> {
+    let mut total_priority: u64 = 0;
+
+    for (i, rucksack) in rucksacks.iter().enumerate() {
+        total_priority += get_rucksack_priority(i + 1, rucksack)?;
+    }
+
+    return Ok(total_priority);
+}
+
+/// Sum of the priorities of the badge carried by each group of three elves.
+/// Fails on a group without one
+pub fn get_group_sum_priorities(rucksacks: &[String]) -> Result<u64, SolveError> {
+    let mut total_priority: u64 = 0;
+
+    for (i, group) in rucksacks.chunks_exact(3).enumerate() {
+        total_priority += get_badge_priority(i + 1, group)?;
+    }
+    return Ok(total_priority);
+}
+
+/// Same as get_sum_of_priorities, with the rucksacks split between threads
+#[cfg(feature = "parallel")]
+pub fn get_sum_of_priorities_parallel(rucksacks: &[String]) -> Result<u64, SolveError> {
+    return rucksacks
+        .par_iter()
+        .enumerate()
+        .map(|(i, rucksack)| get_rucksack_priority(i + 1, rucksack))
+        .sum();
+}
+
+/// Same as get_group_sum_priorities, with the groups split between threads
+#[cfg(feature = "parallel")]
+pub fn get_group_sum_priorities_parallel(rucksacks: &[String]) -> Result<u64, SolveError> {
+    return rucksacks
+        .par_chunks_exact(3)
+        .enumerate()
+        .map(|(i, group)| get_badge_priority(i + 1, group))
+        .sum();
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        #[cfg(feature = "parallel")]
+        return Ok(get_sum_of_priorities_parallel(input)?.into());
+        #[cfg(not(feature = "parallel"))]
+        return Ok(get_sum_of_priorities(input)?.into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        #[cfg(feature = "parallel")]
+        return Ok(get_group_sum_priorities_parallel(input)?.into());
+        #[cfg(not(feature = "parallel"))]
+        return Ok(get_group_sum_priorities(input)?.into());
+    }
+}
+
+impl Streaming for Day03 {
+    fn solve_stream(reader: impl BufRead) -> Result<(Answer, Answer), StreamError> {
+        let mut total_priority: u64 = 0;
+        let mut group_priority: u64 = 0;
+        let mut group: Vec<String> = Vec::new();
+        let mut rucksacks = 0;
+        // The first rucksack or group that couldn't be counted. The rest of
+        // the input is still read, as a parse error further on comes first
+        let mut failed: Option<SolveError> = None;
+
+        iterate_input(stream::lines(reader), |rucksack| {
+            if failed.is_some() {
+                return;
+            }
+            rucksacks += 1;
+            let priority = get_rucksack_priority(rucksacks, rucksack);
+
+            group.push(rucksack.to_string());
+            let badge = match group.len() {
+                3 => Some(get_badge_priority(rucksacks / 3, &group)),
+                _ => None,
+            };
+            if badge.is_some() {
+                group.clear();
+            }
+            match (priority, badge.transpose()) {
+                (Ok(priority), Ok(badge)) => {
+                    total_priority += priority;
+                    group_priority += badge.unwrap_or(0);
+                }
+                (Err(err), _) | (_, Err(err)) => failed = Some(err),
+            }
+        })?;
+
+        if let Some(err) = failed {
+            return Err(err.into());
+        }
+        return Ok((total_priority.into(), group_priority.into()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(all(feature = "parallel", feature = "generate"))]
+    use common::Generator;
+
+    #[test]
+    fn part_one() {
+        let rucksacks = parse_input(EXAMPLE).unwrap();
+        let sum = get_sum_of_priorities(&rucksacks);
+        assert_eq!(sum, Ok(157));
+    }
+
+    #[test]
+    fn part_two() {
+        let rucksacks = parse_input(EXAMPLE).unwrap();
+        let sum = get_group_sum_priorities(&rucksacks);
+        assert_eq!(sum, Ok(70));
+    }
+
+    #[test]
+    fn nothing_in_common() {
+        // The second rucksack has no item in both compartments, which also
+        // leaves the first group without a badge
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\nabcdEFGH\nPmmdzqPrVvPwwTWBwg\n\
+                     wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw\n";
+        let rucksacks = parse_input(input).unwrap();
+
+        let error = "Expected rucksack 2 to have an item in both compartments";
+        assert_eq!(
+            get_sum_of_priorities(&rucksacks),
+            Err(SolveError::new(error))
+        );
+        let error = "Expected the elves of group 1 to share a badge";
+        assert_eq!(
+            get_group_sum_priorities(&rucksacks),
+            Err(SolveError::new(error))
+        );
+        #[cfg(feature = "parallel")]
+        assert_eq!(
+            get_group_sum_priorities_parallel(&rucksacks),
+            Err(SolveError::new(error))
+        );
+
+        match Day03::solve_stream(input.as_bytes()) {
+            Err(StreamError::Solve(err)) => {
+                assert_eq!(err, Day03::part_one(&rucksacks).unwrap_err())
+            }
+            result => panic!("Expected a solve error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn invalid_rucksack() {
+        let error = parse_input("vJrwpWtwJgWr\njqHR-NqRjqzjGDLGL\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+
+        let error = parse_input("vJrwpWtwJgWrh\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 14));
+    }
+    common::stream_matches_parse!(Day03);
+    #[cfg(all(feature = "parallel", feature = "generate"))]
+    #[test]
+    fn parallel_matches_sequential() {
+        let rucksacks = parse_input(&Day03::generate(1, Day03::INPUT_SIZE).0).unwrap();
+
+        let sum = get_sum_of_priorities(&rucksacks);
+        assert_eq!(get_sum_of_priorities_parallel(&rucksacks), sum);
+        let group_sum = get_group_sum_priorities(&rucksacks);
+        assert_eq!(get_group_sum_priorities_parallel(&rucksacks), group_sum);
+    }
+}
@@ -0,0 +1,11 @@
+use common::solution_on_example;
+use day03::*;
+
+solution_on_example!(Day03, 157, 70);
+
+#[test]
+fn priorities_from_outside() {
+    let rucksacks = day03::parse_input("vJrwpWtwJgWrhcsFMMfFFhFp\n").unwrap();
+
+    assert_eq!(get_sum_of_priorities(&rucksacks), Ok(16));
+}
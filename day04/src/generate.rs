@@ -0,0 +1,59 @@
+use crate::Day04;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+fn range(rng: &mut ChaCha8Rng) -> (u32, u32) {
+    let min = rng.gen_range(1..=99);
+    let max = rng.gen_range(min..=99);
+    return (min, max);
+}
+
+// The size is the number of pairs of elves. Pairs are counted as they're
+// drawn, when one range holds the other and when they share a section
+impl Generator for Day04 {
+    const INPUT_SIZE: usize = 1000;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut pairs = String::new();
+        let (mut contains, mut overlaps) = (0u64, 0u64);
+
+        for _ in 0..size.max(1) {
+            let (first, second) = (range(&mut rng), range(&mut rng));
+            pairs.push_str(&format!(
+                "{}-{},{}-{}\n",
+                first.0, first.1, second.0, second.1
+            ));
+
+            if (first.0 <= second.0 && second.1 <= first.1)
+                || (second.0 <= first.0 && first.1 <= second.1)
+            {
+                contains += 1;
+            }
+            if first.0 <= second.1 && second.0 <= first.1 {
+                overlaps += 1;
+            }
+        }
+        return (pairs, (Answer::from(contains), Answer::from(overlaps)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day04::generate(7, 100);
+        assert_eq!(input, Day04::generate(7, 100).0);
+
+        let pairs = Day04::parse(&input).unwrap();
+        assert_eq!(pairs.len(), 100);
+
+        let (one, two) = answers;
+        assert_eq!(Day04::part_one(&pairs), Ok(one));
+        assert_eq!(Day04::part_two(&pairs), Ok(two));
+    }
+}
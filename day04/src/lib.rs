@@ -0,0 +1,244 @@
+//! Day 4: Camp Cleanup
+//!
+//! Counts the pairs of elves where one section range contains the other, and
+//! the pairs whose ranges overlap at all.
+#![warn(missing_docs)]
+
+use common::{stream, Answer, ParseError, Solution, SolveError, StreamError, Streaming};
+use std::{fmt, io::BufRead};
+use tracing::{debug, debug_span};
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day04;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// The sections assigned to an elf, from min to max inclusive
+#[derive(Debug)]
+pub struct Range {
+    min: u32,
+    max: u32,
+}
+
+impl Range {
+    /// A range from min to max, if min isn't past max
+    pub fn new(min: u32, max: u32) -> Option<Self> {
+        if min > max {
+            return None;
+        }
+        return Some(Range { min, max });
+    }
+
+    /// Whether every section of val is also in this range
+    pub fn contains(&self, val: &Self) -> bool {
+        return self.min <= val.min && self.max >= val.max;
+    }
+    /// Whether either range contains the other
+    pub fn mutual_contains(range1: &Self, range2: &Self) -> bool {
+        return range1.contains(range2) || range2.contains(range1);
+    }
+    /// Whether the ranges share at least one section
+    pub fn overlap(&self, val: &Self) -> bool {
+        return self.min <= val.max && self.max >= val.min;
+    }
+    /// Same as overlap, which is already symmetric
+    pub fn mutual_overlap(range1: &Self, range2: &Self) -> bool {
+        return range1.overlap(range2) || range2.overlap(range1);
+    }
+
+    // Parse a range like 2-4, found as token within the given line
+    fn parse(line: usize, text: &str, token: &str) -> Result<Self, ParseError> {
+        let Some((min_str, max_str)) = token.split_once('-') else {
+            let end = &token[token.len()..];
+            return Err(ParseError::at(
+                line,
+                text,
+                end,
+                "Expected a range, like 2-4",
+            ));
+        };
+
+        let min: u32 = min_str
+            .parse::<u32>()
+            .map_err(|_| ParseError::at(line, text, min_str, "Expected a number"))?;
+        let max: u32 = max_str
+            .parse::<u32>()
+            .map_err(|_| ParseError::at(line, text, max_str, "Expected a number"))?;
+
+        if min > max {
+            return Err(ParseError::at(
+                line,
+                text,
+                token,
+                "Expected the start of the range to not be after its end",
+            ));
+        }
+
+        return Ok(Range { min, max });
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Range {{ {}, {} }}", self.min, self.max)
+    }
+}
+
+fn iterate_input<L: AsRef<str>, E: From<ParseError>>(
+    lines: impl Iterator<Item = Result<L, E>>,
+    mut f: impl FnMut(Range, Range),
+) -> Result<(), E> {
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+        if line.is_empty() {
+            continue;
+        }
+        let Some((elf1_str, elf2_str)) = line.split_once(',') else {
+            let end = &line[line.len()..];
+            return Err(ParseError::at(i + 1, line, end, "Expected a pair of ranges").into());
+        };
+
+        let elf1 = Range::parse(i + 1, line, elf1_str)?;
+        let elf2 = Range::parse(i + 1, line, elf2_str)?;
+
+        f(elf1, elf2);
+    }
+    return Ok(());
+}
+
+/// Read every pair of ranges, like 2-4,6-8
+pub fn parse_input(input: &str) -> Result<Vec<(Range, Range)>, ParseError> {
+    let mut pairs = Vec::new();
+    iterate_input(input.lines().map(Ok), |elf1, elf2| pairs.push((elf1, elf2)))?;
+    return Ok(pairs);
+}
+
+/// How many pairs have one range fully containing the other
+pub fn get_total_contains(pairs: &[(Range, Range)]) -> u64 {
+    let mut total_full_contains: u64 = 0;
+
+    for (i, (elf1, elf2)) in pairs.iter().enumerate() {
+        let _pair = debug_span!("pair", number = i + 1).entered();
+        if Range::mutual_contains(elf1, elf2) {
+            debug!(%elf1, %elf2, "one contains the other");
+            total_full_contains += 1;
+        }
+    }
+
+    return total_full_contains;
+}
+
+/// How many pairs have overlapping ranges
+pub fn get_total_overlaps(pairs: &[(Range, Range)]) -> u64 {
+    let mut total_overlaps: u64 = 0;
+
+    for (i, (elf1, elf2)) in pairs.iter().enumerate() {
+        let _pair = debug_span!("pair", number = i + 1).entered();
+        if Range::mutual_overlap(elf1, elf2) {
+            debug!(%elf1, %elf2, "they overlap");
+            total_overlaps += 1;
+        }
+    }
+
+    return total_overlaps;
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_total_contains(input).into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_total_overlaps(input).into());
+    }
+}
+
+impl Streaming for Day04 {
+    fn solve_stream(reader: impl BufRead) -> Result<(Answer, Answer), StreamError> {
+        let mut total_contains: u64 = 0;
+        let mut total_overlaps: u64 = 0;
+
+        iterate_input(stream::lines(reader), |elf1, elf2| {
+            let contains = Range::mutual_contains(&elf1, &elf2);
+            let overlaps = Range::mutual_overlap(&elf1, &elf2);
+            debug!(%elf1, %elf2, contains, overlaps, "pair");
+            total_contains += contains as u64;
+            total_overlaps += overlaps as u64;
+        })?;
+
+        return Ok((total_contains.into(), total_overlaps.into()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn range() -> impl Strategy<Value = Range> {
+        return (0u32..100, 0u32..20).prop_map(|(min, len)| Range {
+            min,
+            max: min + len,
+        });
+    }
+
+    #[test]
+    fn part_one() {
+        let pairs = parse_input(EXAMPLE).unwrap();
+        let total_full_contains: u64 = get_total_contains(&pairs);
+        assert_eq!(total_full_contains, 2);
+    }
+    #[test]
+    fn part_two() {
+        let pairs = parse_input(EXAMPLE).unwrap();
+        let total_overlaps: u64 = get_total_overlaps(&pairs);
+        assert_eq!(total_overlaps, 4);
+    }
+    #[test]
+    fn invalid_pair() {
+        let error = parse_input("2-4,6-8\n2-3,4-x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+
+        let error = parse_input("2-4\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+
+        let error = parse_input("5-4,1-2\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    proptest! {
+        #[test]
+        fn overlap_is_symmetric(a in range(), b in range()) {
+            prop_assert_eq!(a.overlap(&b), b.overlap(&a));
+        }
+
+        #[test]
+        fn contains_implies_overlap(a in range(), b in range()) {
+            if a.contains(&b) {
+                prop_assert!(a.overlap(&b));
+                prop_assert!(Range::mutual_overlap(&a, &b));
+            }
+        }
+
+        #[test]
+        fn contains_matches_sections(a in range(), b in range()) {
+            let every_section = (b.min..=b.max).all(|v| a.min <= v && v <= a.max);
+            let any_section = (b.min..=b.max).any(|v| a.min <= v && v <= a.max);
+            prop_assert_eq!(a.contains(&b), every_section);
+            prop_assert_eq!(a.overlap(&b), any_section);
+        }
+    }
+    common::stream_matches_parse!(Day04);
+}
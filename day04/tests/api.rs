@@ -0,0 +1,16 @@
+use common::solution_on_example;
+use day04::*;
+
+solution_on_example!(Day04, 2, 4);
+
+#[test]
+fn ranges_from_outside() {
+    let outer = Range::new(2, 8).unwrap();
+    let inner = Range::new(3, 7).unwrap();
+    let apart = Range::new(9, 9).unwrap();
+
+    assert!(outer.contains(&inner));
+    assert!(Range::mutual_contains(&inner, &outer));
+    assert!(!outer.overlap(&apart));
+    assert!(Range::new(4, 2).is_none());
+}
@@ -0,0 +1,90 @@
+use crate::Day05;
+use common::{Answer, Generated, Generator};
+use rand::{seq::IteratorRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const STACKS: usize = 9;
+
+// The crate on top of each stack that has one
+fn tops(stacks: &[Vec<char>]) -> Answer {
+    return Answer::Text(stacks.iter().filter_map(|v| v.last()).collect());
+}
+
+// The size is the number of moves. Moves only ever take crates that are on
+// the stack, and are made on two copies of the stacks as they're drawn, one
+// crate at a time and all at once
+impl Generator for Day05 {
+    const INPUT_SIZE: usize = 500;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        let stacks: Vec<Vec<char>> = (0..STACKS)
+            .map(|_| {
+                (0..rng.gen_range(1..=8))
+                    .map(|_| rng.gen_range(b'A'..=b'Z') as char)
+                    .collect()
+            })
+            .collect();
+        let top = stacks.iter().map(|v| v.len()).max().unwrap_or(0);
+
+        let mut drawing = String::new();
+        for level in (0..top).rev() {
+            let row: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => String::from("   "),
+                })
+                .collect();
+            drawing.push_str(&row.join(" "));
+            drawing.push('\n');
+        }
+        let numbers: Vec<String> = (1..=STACKS).map(|v| format!(" {} ", v)).collect();
+        drawing.push_str(&numbers.join(" "));
+        drawing.push_str("\n\n");
+
+        let mut one_at_a_time = stacks.clone();
+        let mut all_at_once = stacks;
+        for _ in 0..size.max(1) {
+            let from = (0..STACKS)
+                .filter(|v| !all_at_once[*v].is_empty())
+                .choose(&mut rng)
+                .unwrap();
+            let to = (0..STACKS).filter(|v| *v != from).choose(&mut rng).unwrap();
+            let quantity = rng.gen_range(1..=all_at_once[from].len());
+
+            let split = all_at_once[from].len() - quantity;
+            let moved = all_at_once[from].split_off(split);
+            all_at_once[to].extend(moved);
+            let moved = one_at_a_time[from].split_off(split);
+            one_at_a_time[to].extend(moved.into_iter().rev());
+            drawing.push_str(&format!(
+                "move {} from {} to {}\n",
+                quantity,
+                from + 1,
+                to + 1
+            ));
+        }
+        return (drawing, (tops(&one_at_a_time), tops(&all_at_once)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day05::generate(7, 100);
+        assert_eq!(input, Day05::generate(7, 100).0);
+
+        let procedure = Day05::parse(&input).unwrap();
+        assert!(Day05::part_one(&procedure).unwrap().to_string().len() <= STACKS);
+
+        let (one, two) = answers;
+        assert_eq!(Day05::part_one(&procedure), Ok(one));
+        assert_eq!(Day05::part_two(&procedure), Ok(two));
+    }
+}
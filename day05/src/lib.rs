@@ -0,0 +1,318 @@
+//! Day 5: Supply Stacks
+//!
+//! Rearranges stacks of crates with a crane moving one crate at a time, and
+//! with one moving many at once, and reads the crates left on top.
+#![warn(missing_docs)]
+
+use common::{Answer, ParseError, Solution, SolveError};
+use tracing::{debug, trace};
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day05;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+fn reverse_stacks<T>(stacks: &mut Vec<Vec<T>>) {
+    for s in stacks {
+        s.reverse();
+    }
+}
+fn get_top_stack(stacks: &Vec<Vec<char>>) -> Vec<char> {
+    let mut top_stack: Vec<char> = Vec::new();
+    for s in stacks {
+        if let Some(top) = s.last() {
+            top_stack.push(top.to_owned());
+        }
+    }
+
+    return top_stack;
+}
+
+// Stack indices are checked while parsing, so they are always in range
+fn move_crates(crate_stacks: &mut [Vec<char>], quantity: usize, from: usize, to: usize) {
+    for _ in 0..quantity {
+        if let Some(el) = crate_stacks[from].pop() {
+            crate_stacks[to].push(el);
+        }
+    }
+}
+fn move_crates_at_once(crate_stacks: &mut [Vec<char>], quantity: usize, from: usize, to: usize) {
+    let from_stack = &mut crate_stacks[from];
+    let mut stack = from_stack.split_off(from_stack.len().saturating_sub(quantity));
+
+    crate_stacks[to].append(&mut stack);
+}
+
+/// A single step of the procedure: move quantity crates between two stacks
+#[derive(Debug)]
+pub struct Movement {
+    quantity: usize,
+    from: usize,
+    to: usize,
+}
+
+/// Crate stacks, bottom to top, along with the rearrangement procedure
+#[derive(Debug)]
+pub struct Procedure {
+    crate_stacks: Vec<Vec<char>>,
+    movements: Vec<Movement>,
+}
+
+impl Procedure {
+    /// The stacks before any crate is moved, bottom to top
+    pub fn crate_stacks(&self) -> &[Vec<char>] {
+        return &self.crate_stacks;
+    }
+}
+
+// Read the drawing of the stacks, up to and including the line numbering them
+fn get_crate_stacks<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut crate_stacks: Vec<Vec<char>> = Vec::new();
+    let mut line_count = 0;
+
+    for (i, line) in lines {
+        line_count = i + 1;
+        if line.trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+            for (n, number) in line.split_whitespace().enumerate() {
+                if number.parse::<usize>() != Ok(n + 1) {
+                    let message = format!("Expected stack number {}", n + 1);
+                    return Err(ParseError::at(i + 1, line, number, message));
+                }
+                if n >= crate_stacks.len() {
+                    crate_stacks.push(Vec::new());
+                }
+            }
+            reverse_stacks(&mut crate_stacks);
+            return Ok(crate_stacks);
+        }
+
+        for (j, c) in line.char_indices() {
+            let valid = match j % 4 {
+                0 => c == '[' || c == ' ',
+                1 => c.is_ascii_uppercase() || c == ' ',
+                2 => c == ']' || c == ' ',
+                _ => c == ' ',
+            };
+            if !valid {
+                let message = "Expected a crate like [A], or an empty space";
+                return Err(ParseError::at(i + 1, line, &line[j..], message));
+            }
+            if j % 4 != 1 {
+                continue;
+            }
+
+            let stack_index = j / 4;
+            while crate_stacks.len() <= stack_index {
+                crate_stacks.push(Vec::new());
+            }
+            if c != ' ' {
+                crate_stacks[stack_index].push(c);
+            }
+        }
+    }
+
+    return Err(ParseError::new(
+        line_count + 1,
+        1,
+        "",
+        "Expected a line numbering the crate stacks",
+    ));
+}
+
+// Read every "move N from A to B" line, checking there are always enough
+// crates to move. Both cranes move the same number of crates, so the
+// height of each stack is known without moving them
+fn get_crate_movements<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    crate_stacks: &[Vec<char>],
+) -> Result<Vec<Movement>, ParseError> {
+    let mut movements = Vec::new();
+    let mut heights: Vec<usize> = crate_stacks.iter().map(|v| v.len()).collect();
+
+    for (i, line) in lines {
+        if line.is_empty() {
+            continue;
+        }
+        let mut words = line.split_whitespace();
+        let mut values = [0; 3];
+
+        for (keyword, value) in ["move", "from", "to"].iter().zip(values.iter_mut()) {
+            match words.next() {
+                Some(word) if word == *keyword => {}
+                Some(word) => {
+                    let message = format!("Expected '{}'", keyword);
+                    return Err(ParseError::at(i + 1, line, word, message));
+                }
+                None => {
+                    let message = format!("Expected '{}'", keyword);
+                    return Err(ParseError::at(i + 1, line, &line[line.len()..], message));
+                }
+            }
+            let word = words.next().unwrap_or(&line[line.len()..]);
+            *value = word
+                .parse::<usize>()
+                .map_err(|_| ParseError::at(i + 1, line, word, "Expected a number"))?;
+
+            if *keyword != "move" && (*value == 0 || *value > heights.len()) {
+                let message = format!("Expected a stack from 1 to {}", heights.len());
+                return Err(ParseError::at(i + 1, line, word, message));
+            }
+        }
+        if let Some(word) = words.next() {
+            let message = "Expected the end of the movement";
+            return Err(ParseError::at(i + 1, line, word, message));
+        }
+
+        let [quantity, from, to] = values;
+        let (from, to) = (from - 1, to - 1);
+        if heights[from] < quantity {
+            let message = format!("Expected at most {} crates to move", heights[from]);
+            return Err(ParseError::new(i + 1, 1, line, message));
+        }
+        heights[from] -= quantity;
+        heights[to] += quantity;
+
+        movements.push(Movement { quantity, from, to });
+    }
+    return Ok(movements);
+}
+
+fn do_crate_movements(
+    crate_stacks: &mut [Vec<char>],
+    movements: &[Movement],
+    multiple_movement: bool,
+) {
+    for movement in movements {
+        // Stacks are numbered from 1 in the procedure
+        debug!(
+            quantity = movement.quantity,
+            from = movement.from + 1,
+            to = movement.to + 1,
+            "move"
+        );
+        if multiple_movement {
+            move_crates_at_once(crate_stacks, movement.quantity, movement.from, movement.to);
+        } else {
+            move_crates(crate_stacks, movement.quantity, movement.from, movement.to);
+        }
+        trace!(stacks = ?crate_stacks, "after the move");
+    }
+}
+
+/// Read the drawing of the stacks, then every move of the procedure
+pub fn parse_input(input: &str) -> Result<Procedure, ParseError> {
+    let mut lines = input.lines().enumerate();
+
+    let crate_stacks = get_crate_stacks(&mut lines)?;
+    let movements = get_crate_movements(&mut lines, &crate_stacks)?;
+
+    return Ok(Procedure {
+        crate_stacks,
+        movements,
+    });
+}
+
+/// The stacks once the whole procedure is done, bottom to top, with a crane
+/// moving many crates at once or one at a time
+pub fn get_rearranged_stacks(procedure: &Procedure, multiple_movement: bool) -> Vec<Vec<char>> {
+    let mut crate_stacks = procedure.crate_stacks.clone();
+    do_crate_movements(&mut crate_stacks, &procedure.movements, multiple_movement);
+    return crate_stacks;
+}
+
+/// The crates on top of each stack when the crane moves one crate at a time
+pub fn get_top_stack_one(procedure: &Procedure) -> String {
+    let crate_stacks = get_rearranged_stacks(procedure, false);
+
+    let top_stack = get_top_stack(&crate_stacks);
+    return top_stack.iter().collect();
+}
+
+/// The crates on top of each stack when the crane moves many crates at once
+pub fn get_top_stack_multiple(procedure: &Procedure) -> String {
+    let crate_stacks = get_rearranged_stacks(procedure, true);
+
+    let top_stack = get_top_stack(&crate_stacks);
+    return top_stack.iter().collect();
+}
+
+/// The stacks drawn the way the puzzle does, top crates first and the number
+/// of each stack underneath
+pub fn draw_crate_stacks(crate_stacks: &[Vec<char>]) -> String {
+    let height = crate_stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut lines = Vec::new();
+
+    for level in (0..height).rev() {
+        let cells: Vec<String> = crate_stacks
+            .iter()
+            .map(|s| match s.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => String::from("   "),
+            })
+            .collect();
+        lines.push(cells.join(" ").trim_end().to_string());
+    }
+    let numbers: Vec<String> = (1..=crate_stacks.len())
+        .map(|n| format!("{:^3}", n))
+        .collect();
+    lines.push(numbers.join(" ").trim_end().to_string());
+
+    return lines.join("\n");
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Procedure;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_top_stack_one(input).into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_top_stack_multiple(input).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let procedure = parse_input(EXAMPLE).unwrap();
+        let top_stack = get_top_stack_one(&procedure);
+        assert_eq!(top_stack, "CMZ");
+    }
+    #[test]
+    fn part_two() {
+        let procedure = parse_input(EXAMPLE).unwrap();
+        let top_stack = get_top_stack_multiple(&procedure);
+        assert_eq!(top_stack, "MCD");
+    }
+    #[test]
+    fn invalid_procedure() {
+        let stacks = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+
+        let error = parse_input(&format!("{}move 1 from 4 to 1\n", stacks)).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 13));
+
+        let error = parse_input(&format!("{}move 4 from 1 to 2\n", stacks)).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 1));
+
+        let error = parse_input(&format!("{}move 1 frm 2 to 1\n", stacks)).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 8));
+
+        let error = parse_input("[Z} [M]\n 1   2 \n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+}
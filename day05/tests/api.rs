@@ -0,0 +1,12 @@
+use common::solution_on_example;
+use day05::*;
+
+solution_on_example!(Day05, "CMZ", "MCD");
+
+#[test]
+fn crane_from_outside() {
+    let procedure = day05::parse_input(EXAMPLE).unwrap();
+
+    assert_eq!(get_top_stack_one(&procedure), "CMZ");
+    assert_eq!(get_top_stack_multiple(&procedure), "MCD");
+}
@@ -0,0 +1,12 @@
+common::snapshot_example_output!(env!("CARGO_BIN_EXE_day05"));
+
+#[test]
+fn stack_drawings() {
+    let procedure = day05::parse_input(day05::EXAMPLE).unwrap();
+
+    insta::assert_snapshot!("before", day05::draw_crate_stacks(procedure.crate_stacks()));
+    for (name, multiple_movement) in [("crate_mover_9000", false), ("crate_mover_9001", true)] {
+        let crate_stacks = day05::get_rearranged_stacks(&procedure, multiple_movement);
+        insta::assert_snapshot!(name, day05::draw_crate_stacks(&crate_stacks));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::Day06;
+use common::{Answer, Generated, Generator};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const PACKET_LENGTH: usize = 4;
+const MARKER_LENGTH: usize = 14;
+
+// The size is the length of the datastream. Up to the one planted run of
+// fourteen different letters it only uses two, and the letter right before
+// the run is the same as its first, so no marker ends before it does: the
+// packet marker ends four letters in, the message marker at its end
+impl Generator for Day06 {
+    const INPUT_SIZE: usize = 4096;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let size = size.max(MARKER_LENGTH);
+
+        let start = rng.gen_range((size - MARKER_LENGTH) / 2..=size - MARKER_LENGTH);
+        let mut stream: Vec<u8> = (0..size)
+            .map(|i| match i < start {
+                true => rng.gen_range(b'a'..=b'b'),
+                false => rng.gen_range(b'a'..=b'l'),
+            })
+            .collect();
+
+        let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+        letters.shuffle(&mut rng);
+        stream[start..start + MARKER_LENGTH].copy_from_slice(&letters[..MARKER_LENGTH]);
+        if start > 0 {
+            stream[start - 1] = letters[0];
+        }
+
+        let answers = (
+            Answer::from(start + PACKET_LENGTH),
+            Answer::from(start + MARKER_LENGTH),
+        );
+        return (String::from_utf8(stream).unwrap() + "\n", answers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let input = Day06::generate(7, 1000).0;
+        assert_eq!(input, Day06::generate(7, 1000).0);
+
+        let datastream = Day06::parse(&input).unwrap();
+        assert!(crate::find_marker(&datastream, MARKER_LENGTH) >= 500);
+
+        for size in [0, 14, 20, 1000] {
+            let (input, answers) = Day06::generate(7, size);
+            let datastream = Day06::parse(&input).unwrap();
+            assert_eq!(datastream.len(), size.max(MARKER_LENGTH));
+
+            let (one, two) = answers;
+            assert_eq!(Day06::part_one(&datastream), Ok(one));
+            assert_eq!(Day06::part_two(&datastream), Ok(two));
+        }
+    }
+}
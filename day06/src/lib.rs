@@ -0,0 +1,291 @@
+//! Day 6: Tuning Trouble
+//!
+//! Finds how many characters of a datastream have to be read before the first
+//! start-of-packet and start-of-message markers.
+#![warn(missing_docs)]
+
+use common::{Answer, ParseError, Solution, SolveError, StreamError, Streaming};
+use std::{collections::VecDeque, io::BufRead};
+use tracing::debug;
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day06;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+// Longest line kept around to show in an error, when streaming
+const MAX_ERROR_TEXT: usize = 4096;
+
+fn check_repeats(window: &[u8]) -> bool {
+    let mut i: usize = 0;
+    while i < window.len() {
+        let mut j = i + 1;
+        while j < window.len() {
+            if window[i] == window[j] {
+                return true;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    return false;
+}
+
+// Reads a datastream one character at a time, keeping only the last few, until
+// the first marker ends
+struct MarkerFinder {
+    marker_length: usize,
+    window: VecDeque<u8>,
+    read: u64,
+    found: Option<u64>,
+}
+
+impl MarkerFinder {
+    fn new(marker_length: usize) -> Self {
+        return MarkerFinder {
+            marker_length,
+            window: VecDeque::with_capacity(marker_length + 1),
+            read: 0,
+            found: None,
+        };
+    }
+
+    fn push(&mut self, c: u8) {
+        if self.found.is_some() {
+            return;
+        }
+        self.read += 1;
+        if self.window.len() == self.marker_length {
+            self.window.pop_front();
+        }
+        self.window.push_back(c);
+        if self.window.len() == self.marker_length && !check_repeats(self.window.make_contiguous())
+        {
+            let marker = String::from_utf8_lossy(self.window.make_contiguous());
+            debug!(length = self.marker_length, %marker, read = self.read, "marker");
+            self.found = Some(self.read);
+        }
+    }
+}
+
+/// How many characters are read before the first run of marker_length
+/// different characters ends, or 0 if there is none
+pub fn find_marker(string: &str, marker_length: usize) -> u64 {
+    let mut finder = MarkerFinder::new(marker_length);
+    for c in string.bytes() {
+        finder.push(c);
+    }
+    return finder.found.unwrap_or(0);
+}
+
+/// Read the datastream, which must only have lowercase letters
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    for (i, line) in input.lines().enumerate() {
+        if let Some((j, _)) = line.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at(
+                i + 1,
+                line,
+                &line[j..],
+                "Expected a character from a to z",
+            ));
+        }
+    }
+    return Ok(input.trim_end().to_string());
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(find_marker(input, 4).into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(find_marker(input, 14).into());
+    }
+}
+
+// The datastream can be a single huge line, so it's read in chunks rather
+// than line by line. Line endings are fed to the finders as they come, but
+// trailing ones are trimmed when parsing, so a marker found in them doesn't
+// count once the datastream ends
+impl Streaming for Day06 {
+    fn solve_stream(mut reader: impl BufRead) -> Result<(Answer, Answer), StreamError> {
+        let mut packet = MarkerFinder::new(4);
+        let mut message = MarkerFinder::new(14);
+        // Line endings read since the last character, and whether the last
+        // of them was a '\r' still waiting for its '\n'
+        let mut pending: u64 = 0;
+        let mut carriage_return = false;
+        let mut read: u64 = 0;
+        let mut feed = |c| {
+            packet.push(c);
+            message.push(c);
+            read += 1;
+        };
+
+        let mut line = 1;
+        let mut column = 0;
+        // The current line, to show in an error. Once one is found, the rest
+        // of its line is read before giving up
+        let mut text = Vec::new();
+        let mut failed = None;
+        let error = |line, column, text: &[u8]| {
+            let text = String::from_utf8_lossy(text);
+            ParseError::new(line, column, &text, "Expected a character from a to z")
+        };
+
+        loop {
+            let chunk = reader.fill_buf()?;
+            if chunk.is_empty() {
+                break;
+            }
+            for c in chunk {
+                if let Some(column) = failed {
+                    if *c == b'\n' || text.len() >= MAX_ERROR_TEXT {
+                        let text = text.strip_suffix(b"\r").unwrap_or(&text);
+                        return Err(error(line, column, text).into());
+                    }
+                    text.push(*c);
+                    continue;
+                }
+                if *c == b'\n' {
+                    feed(*c);
+                    pending += 1;
+                    carriage_return = false;
+                    line += 1;
+                    column = 0;
+                    text.clear();
+                    continue;
+                }
+                if carriage_return {
+                    failed = Some(column);
+                }
+                column += 1;
+                if text.len() < MAX_ERROR_TEXT {
+                    text.push(*c);
+                }
+                match c {
+                    _ if failed.is_some() => {}
+                    b'\r' => {
+                        feed(*c);
+                        pending += 1;
+                        carriage_return = true;
+                    }
+                    b'a'..=b'z' => {
+                        feed(*c);
+                        pending = 0;
+                    }
+                    _ => failed = Some(column),
+                }
+            }
+            let length = chunk.len();
+            reader.consume(length);
+        }
+        if let Some(column) = failed {
+            return Err(error(line, column, &text).into());
+        }
+        if carriage_return {
+            return Err(error(line, column, &text).into());
+        }
+
+        let trimmed = read - pending;
+        let packet = packet.found.filter(|v| *v <= trimmed).unwrap_or(0);
+        let message = message.found.filter(|v| *v <= trimmed).unwrap_or(0);
+        return Ok((packet.into(), message.into()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Position right after the first window of distinct characters, or 0
+    fn brute_force_marker(string: &str, marker_length: usize) -> u64 {
+        let bytes = string.as_bytes();
+        for end in marker_length..=bytes.len() {
+            let window = &bytes[end - marker_length..end];
+            let distinct = window
+                .iter()
+                .enumerate()
+                .all(|(i, c)| !window[i + 1..].contains(c));
+            if distinct {
+                return end as u64;
+            }
+        }
+        return 0;
+    }
+
+    fn do_test(marker_length: usize, expected: u64) {
+        let mut sequence = String::new();
+        let mut sum_of_markers = 0;
+        for c in EXAMPLE.chars() {
+            if c == '\n' {
+                let marker = find_marker(&sequence, marker_length);
+                sum_of_markers += marker;
+                sequence.clear();
+                continue;
+            }
+            sequence.push(c);
+        }
+        assert_eq!(sum_of_markers, expected);
+    }
+
+    #[test]
+    fn part_one() {
+        do_test(4, 39);
+    }
+    #[test]
+    fn part_two() {
+        do_test(14, 120);
+    }
+    #[test]
+    fn invalid_datastream() {
+        let error = parse_input("mjqjpqmgbljsphdztnvjfqwrcgsmlb\nbvwbjp1plbgvbhsrlpgdmjqwftvncz\n")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+
+        let input = "mjqjp\r\nbv\rwb\n";
+        let error = parse_input(input).unwrap_err();
+        match Day06::solve_stream(input.as_bytes()) {
+            Err(StreamError::Parse(streamed)) => assert_eq!(streamed, error),
+            result => panic!("Expected a parse error, got {:?}", result),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn marker_matches_brute_force(string in "[a-h]{0,40}", marker_length in 1usize..=8) {
+            prop_assert_eq!(
+                find_marker(&string, marker_length),
+                brute_force_marker(&string, marker_length)
+            );
+        }
+
+        // Blank lines and line endings anywhere, and markers that only
+        // show up if the trailing ones were kept
+        #[test]
+        fn stream_matches_parse_on_lines(string in "(abc|[a-d]|\n|\r\n){0,40}") {
+            let parsed = Day06::parse(&string).unwrap();
+            let streamed = Day06::solve_stream(string.as_bytes()).unwrap();
+            prop_assert_eq!(
+                streamed,
+                (
+                    Day06::part_one(&parsed).unwrap(),
+                    Day06::part_two(&parsed).unwrap()
+                )
+            );
+        }
+    }
+    common::stream_matches_parse!(Day06);
+}
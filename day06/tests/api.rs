@@ -0,0 +1,10 @@
+use common::solution_on_example;
+use day06::*;
+
+solution_on_example!(Day06, 7, 19);
+
+#[test]
+fn markers_from_outside() {
+    assert_eq!(find_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 4), 5);
+    assert_eq!(find_marker("nppdvjthqldpwncqszvftbrmjlhg", 14), 23);
+}
@@ -0,0 +1 @@
+common::snapshot_example_output!(env!("CARGO_BIN_EXE_day06"));
@@ -0,0 +1,138 @@
+use crate::Day07;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+struct Directory {
+    name: String,
+    files: Vec<(u32, String)>,
+    children: Vec<usize>,
+}
+
+fn random_name(rng: &mut ChaCha8Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let mut name: String = (0..rng.gen_range(3..=8))
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect();
+        if rng.gen_bool(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.gen_range(b'a'..=b'z') as char));
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+// Browse into every directory, listing it first
+fn write_directory(directories: &[Directory], index: usize, transcript: &mut String) {
+    let directory = &directories[index];
+    transcript.push_str("$ ls\n");
+    for child in &directory.children {
+        transcript.push_str(&format!("dir {}\n", directories[*child].name));
+    }
+    for (size, name) in &directory.files {
+        transcript.push_str(&format!("{} {}\n", size, name));
+    }
+    for child in &directory.children {
+        transcript.push_str(&format!("$ cd {}\n", directories[*child].name));
+        write_directory(directories, *child, transcript);
+        transcript.push_str("$ cd ..\n");
+    }
+}
+
+// Same limits as the solution
+const SMALL_SIZE: u64 = 100000;
+const DISK_SIZE: u64 = 70000000;
+const REQUIRED_SIZE: u64 = 30000000;
+
+// The size of every directory, counting what's inside the ones in it. Each
+// is placed after its parent, so they're added up from the last one back
+fn directory_sizes(directories: &[Directory]) -> Vec<u64> {
+    let mut sizes = vec![0; directories.len()];
+    for (i, directory) in directories.iter().enumerate().rev() {
+        let files: u64 = directory.files.iter().map(|(size, _)| *size as u64).sum();
+        sizes[i] = files + directory.children.iter().map(|v| sizes[*v]).sum::<u64>();
+    }
+    return sizes;
+}
+
+// The size is the number of directories, each placed in a random one before
+// it. Like the solution, the answers only count the root as the fallback for
+// the directory to delete
+impl Generator for Day07 {
+    const INPUT_SIZE: usize = 170;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut directories: Vec<Directory> = Vec::new();
+        let mut names = Vec::new();
+
+        for i in 0..size.max(1) {
+            let name = match i {
+                0 => String::from("/"),
+                _ => {
+                    let parent = rng.gen_range(0..i);
+                    directories[parent].children.push(i);
+                    random_name(&mut rng, &mut names[parent])
+                }
+            };
+            let mut taken = HashSet::new();
+            let files = (0..rng.gen_range(0..=5))
+                .map(|_| {
+                    (
+                        rng.gen_range(1000..=330000),
+                        random_name(&mut rng, &mut taken),
+                    )
+                })
+                .collect();
+            directories.push(Directory {
+                name,
+                files,
+                children: Vec::new(),
+            });
+            names.push(taken);
+        }
+
+        let mut transcript = String::from("$ cd /\n");
+        write_directory(&directories, 0, &mut transcript);
+
+        let sizes = directory_sizes(&directories);
+        let used = sizes[0];
+        let small: u64 = sizes[1..].iter().filter(|v| **v < SMALL_SIZE).sum();
+        let unused = DISK_SIZE.saturating_sub(used);
+        let smallest = sizes[1..]
+            .iter()
+            .filter(|v| unused + **v >= REQUIRED_SIZE)
+            .fold(used, |smallest, v| smallest.min(*v));
+        return (transcript, (Answer::from(small), Answer::from(smallest)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeTrait;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day07::generate(7, 100);
+        assert_eq!(input, Day07::generate(7, 100).0);
+
+        let root = Day07::parse(&input).unwrap();
+        let mut directories = 0;
+        crate::bfs_iterate(&root, |child| {
+            if child.borrow().is_directory {
+                directories += 1;
+            }
+        });
+        assert_eq!(directories, 99);
+        assert!(!root.get_children().is_empty());
+
+        let (one, two) = answers;
+        assert_eq!(Day07::part_one(&root), Ok(one));
+        assert_eq!(Day07::part_two(&root), Ok(two));
+    }
+}
@@ -0,0 +1,381 @@
+//! Day 7: No Space Left On Device
+//!
+//! Rebuilds a filesystem tree from a terminal session, then sizes up its
+//! directories to find the ones worth deleting.
+#![warn(missing_docs)]
+
+use common::{Answer, ParseError, Solution, SolveError};
+use std::{cell::RefCell, collections::VecDeque, path::PathBuf, rc::Rc};
+use tracing::debug;
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day07;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// A node shared between its parent and the nodes below it
+pub type Ref<T> = Rc<RefCell<T>>;
+
+/// A file or a directory. Directories have no size of their own
+#[derive(PartialEq, Debug)]
+pub struct Node {
+    name: String,
+    size: u32,
+    is_directory: bool,
+    path: PathBuf,
+    parent: Option<Ref<Node>>,
+    children: Vec<Ref<Node>>,
+}
+
+/// Walking and building the tree through shared nodes
+pub trait NodeTrait {
+    /// Add node as a child, unless there's already one with its name
+    fn add(&self, node: &Self);
+    /// The directory containing this node, none for the root
+    fn get_parent(&self) -> Option<Self>
+    where
+        Self: Sized;
+    /// The nodes directly inside this one
+    fn get_children(&self) -> Vec<Self>
+    where
+        Self: Sized;
+    /// The name of the file or directory
+    fn get_name(&self) -> String;
+    /// The full path from the root
+    fn get_path(&self) -> PathBuf;
+    /// The full path from the root, as a string
+    fn get_path_str(&self) -> String;
+}
+
+impl NodeTrait for Ref<Node> {
+    fn add(&self, node: &Self) {
+        let mut self_mut = self.borrow_mut();
+        // Listing a directory again doesn't add its nodes twice
+        let name = node.get_name();
+        if self_mut
+            .children
+            .iter()
+            .any(|child| child.borrow().name == name)
+        {
+            return;
+        }
+        self_mut.children.push(node.to_owned());
+        let mut path = self_mut.path.clone();
+        path.push(node.borrow().name.clone());
+        node.borrow_mut().path = path;
+        node.borrow_mut().parent = Some(self.to_owned());
+    }
+
+    fn get_parent(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        return self.borrow().parent.clone();
+    }
+    fn get_children(&self) -> Vec<Self>
+    where
+        Self: Sized,
+    {
+        return self.borrow().children.clone();
+    }
+    fn get_name(&self) -> String {
+        return self.borrow().name.clone();
+    }
+    fn get_path(&self) -> PathBuf {
+        return self.borrow().path.clone();
+    }
+    fn get_path_str(&self) -> String {
+        return self.borrow().path.to_str().unwrap_or("").to_string();
+    }
+}
+
+impl Node {
+    /// A node on its own, until it's added to a directory
+    pub fn new(name: String, size: u32, is_directory: bool) -> Ref<Self> {
+        let value = Node {
+            name,
+            size,
+            path: PathBuf::from("/"),
+            is_directory,
+            parent: None,
+            children: Vec::new(),
+        };
+        return Rc::new(RefCell::new(value));
+    }
+    // Parse a listed node, like "dir a" or "14848514 b.txt"
+    fn parse_from(line: usize, text: &str) -> Result<Ref<Self>, ParseError> {
+        let mut splitted = text.split_whitespace();
+
+        let end = &text[text.len()..];
+        let size = splitted.next().unwrap_or(end);
+        let Some(name) = splitted.next() else {
+            return Err(ParseError::at(line, text, end, "Expected a name"));
+        };
+        if let Some(word) = splitted.next() {
+            return Err(ParseError::at(
+                line,
+                text,
+                word,
+                "Expected the end of the line",
+            ));
+        }
+
+        if size == "dir" {
+            return Ok(Node::new(name.to_string(), 0, true));
+        }
+        let size: u32 = size
+            .parse()
+            .map_err(|_| ParseError::at(line, text, size, "Expected 'dir' or a file size"))?;
+        return Ok(Node::new(name.to_string(), size, false));
+    }
+}
+
+// Iterate all the nodes using the BFS algorithm
+fn bfs_iterate(root: &Ref<Node>, mut f: impl FnMut(&Ref<Node>)) {
+    let mut to_visit: VecDeque<Ref<Node>> = VecDeque::new();
+    let mut visited: Vec<Ref<Node>> = Vec::new();
+
+    to_visit.push_back(root.clone());
+
+    while !to_visit.is_empty() {
+        let v = to_visit.pop_front();
+        if v.is_none() {
+            return;
+        }
+        let element = v.unwrap();
+        let children = element.get_children();
+        for child in children.iter() {
+            if !visited.iter().any(|v| Rc::ptr_eq(v, child)) {
+                visited.push(child.clone());
+
+                to_visit.push_back(child.clone());
+
+                f(child);
+            }
+        }
+    }
+}
+
+// Get size of directory
+fn get_size(node: &Ref<Node>) -> u64 {
+    let mut size: u64 = 0;
+    bfs_iterate(node, |child| {
+        size += Into::<u64>::into(child.borrow().size);
+    });
+    return size;
+}
+
+// Get concurrent size of directory
+// - /a
+//    - /e
+//      - file (30)
+//    - file (20)
+// If /a/e sizes 30, then /a sizes 50. So, get_concurrent_size (/a) returns 80
+fn get_concurrent_size(node: &Ref<Node>, mut size: u64) -> u64 {
+    let n = node.borrow();
+    if !n.is_directory {
+        return n.size.into();
+    }
+
+    for child in node.get_children() {
+        size = size.saturating_add(get_concurrent_size(&child, size));
+    }
+    return size;
+}
+
+/// Sum of the sizes of every directory smaller than top_size, counting
+/// files again for each directory they're in
+pub fn get_total_size(root: &Ref<Node>, top_size: u32) -> u64 {
+    let mut total_size = 0;
+
+    bfs_iterate(root, |child| {
+        if child.borrow().is_directory {
+            let dir_size = get_concurrent_size(child, 0);
+            debug!(path = %child.get_path().display(), size = dir_size, "directory");
+            if dir_size < top_size.into() {
+                total_size += dir_size;
+            }
+        }
+    });
+
+    return total_size;
+}
+
+/// Size of the smallest directory that frees up enough space when deleted
+pub fn find_smallest_node_size_to_delete(
+    root: &Ref<Node>,
+    total_disk_size: u64,
+    required_size: u64,
+) -> u64 {
+    let total_used_size: u64 = get_size(root);
+    let unused_size: u64 = total_disk_size.saturating_sub(total_used_size);
+
+    let mut smallest_node_size = total_used_size;
+    debug!(used = total_used_size, unused = unused_size, "disk");
+
+    bfs_iterate(root, |child| {
+        if child.borrow().is_directory {
+            let dir_size = get_size(child);
+            debug!(path = %child.get_path().display(), size = dir_size, "directory");
+
+            if unused_size + dir_size >= required_size && dir_size < smallest_node_size {
+                smallest_node_size = dir_size;
+            }
+        }
+    });
+
+    return smallest_node_size;
+}
+
+// Add a line for node and every node below it, depth levels in
+fn draw_node(node: &Ref<Node>, depth: usize, lines: &mut Vec<String>) {
+    let n = node.borrow();
+    let kind = match n.is_directory {
+        true => String::from("dir"),
+        false => format!("file, size={}", n.size),
+    };
+    lines.push(format!("{}- {} ({})", "  ".repeat(depth), n.name, kind));
+    for child in n.children.iter() {
+        draw_node(child, depth + 1, lines);
+    }
+}
+
+/// The tree as the puzzle draws it, a line per node indented under its
+/// directory, in the order they were listed
+pub fn draw_tree(root: &Ref<Node>) -> String {
+    let mut lines = Vec::new();
+    draw_node(root, 0, &mut lines);
+    return lines.join("\n");
+}
+
+// Get relative node to current
+fn get_relative_node_at(root: &Ref<Node>, current: &Ref<Node>, path: &str) -> Option<Ref<Node>> {
+    if path == "/" {
+        return Some(root.clone());
+    }
+
+    let mut target = current.get_path();
+    if path == ".." {
+        return current.get_parent();
+    } else {
+        target.push(path);
+    }
+
+    let children = current.get_children();
+    return children
+        .into_iter()
+        .find(|child| child.borrow().path == target);
+}
+
+/// Parse the input file to create all the nodes and append them to their parents
+pub fn parse_input(input: &str, root: &Ref<Node>) -> Result<(), ParseError> {
+    let lines = input.lines();
+
+    let mut current: Ref<Node> = root.clone();
+
+    for (i, line) in lines.enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with('$') {
+            let mut spaces = line.split_whitespace();
+            spaces.next();
+            let end = &line[line.len()..];
+            let command = spaces.next().unwrap_or(end);
+            match command {
+                "cd" => {
+                    let Some(arg) = spaces.next() else {
+                        return Err(ParseError::at(i + 1, line, end, "Expected a directory"));
+                    };
+                    current = get_relative_node_at(root, &current, arg).ok_or_else(|| {
+                        ParseError::at(i + 1, line, arg, "Expected a known directory")
+                    })?;
+                }
+                "ls" => {}
+                _ => {
+                    let message = "Expected a command, cd or ls";
+                    return Err(ParseError::at(i + 1, line, command, message));
+                }
+            }
+            continue;
+        }
+        let node = Node::parse_from(i + 1, line)?;
+        current.add(&node);
+    }
+    return Ok(());
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Ref<Node>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let root = Node::new(String::from("/"), 0, true);
+        parse_input(input, &root)?;
+        return Ok(root);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_total_size(input, 100000).into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(find_smallest_node_size_to_delete(input, 70000000, 30000000).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let root = Node::new(String::from("/"), 0, true);
+        parse_input(EXAMPLE, &root).unwrap();
+
+        let size = get_total_size(&root, 100000);
+        println!("Size: {}", size);
+
+        assert_eq!(size, 95437);
+    }
+    #[test]
+    fn part_two() {
+        let root = Node::new(String::from("/"), 0, true);
+        parse_input(EXAMPLE, &root).unwrap();
+
+        let size = find_smallest_node_size_to_delete(&root, 70000000, 30000000);
+        println!("Size: {}", size);
+
+        assert_eq!(size, 24933642);
+    }
+    #[test]
+    fn invalid_terminal_output() {
+        let root = Node::new(String::from("/"), 0, true);
+        let error = parse_input("$ cd /\n$ ls\ndir a\n12a b.txt\n", &root).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+
+        let root = Node::new(String::from("/"), 0, true);
+        let error = parse_input("$ cd /\n$ ls\ndir a\n$ cd e\n", &root).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 6));
+
+        let root = Node::new(String::from("/"), 0, true);
+        let error = parse_input("$ cd /\n$ dir\n", &root).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+    #[test]
+    fn listed_twice() {
+        let root = Node::new(String::from("/"), 0, true);
+        parse_input(
+            "$ cd /\n$ ls\ndir a\n10 b.txt\n$ ls\ndir a\n10 b.txt\n",
+            &root,
+        )
+        .unwrap();
+
+        assert_eq!(root.get_children().len(), 2);
+        assert_eq!(get_size(&root), 10);
+    }
+}
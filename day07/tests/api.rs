@@ -0,0 +1,19 @@
+use common::solution_on_example;
+use day07::*;
+
+solution_on_example!(Day07, 95437, 24933642);
+
+#[test]
+fn tree_from_outside() {
+    let root = Node::new(String::from("/"), 0, true);
+    day07::parse_input(
+        "$ cd /\n$ ls\ndir a\n10 b.txt\n$ cd a\n$ ls\n20 c.txt\n",
+        &root,
+    )
+    .unwrap();
+
+    let children = root.get_children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0].get_path_str(), "/a");
+    assert_eq!(get_total_size(&root, 25), 20);
+}
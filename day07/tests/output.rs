@@ -0,0 +1,9 @@
+common::snapshot_example_output!(env!("CARGO_BIN_EXE_day07"));
+
+#[test]
+fn tree_dump() {
+    let root = day07::Node::new(String::from("/"), 0, true);
+    day07::parse_input(day07::EXAMPLE, &root).unwrap();
+
+    insta::assert_snapshot!(day07::draw_tree(&root));
+}
@@ -0,0 +1,87 @@
+use crate::Day08;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Walk a line of trees from its start, and for each tree find whether it's
+// visible from the start and how far it can see back towards it, from where
+// each height was last seen
+fn sweep(line: &[u8]) -> Vec<(bool, u64)> {
+    let mut last_seen: [Option<usize>; 10] = [None; 10];
+    let mut trees = Vec::with_capacity(line.len());
+    for (i, height) in line.iter().enumerate() {
+        let blocking = last_seen[*height as usize..].iter().flatten().max();
+        trees.push((blocking.is_none(), (i - blocking.unwrap_or(&0)) as u64));
+        last_seen[*height as usize] = Some(i);
+    }
+    return trees;
+}
+
+// Sweep a line of the grid both ways, adding the results to each tree in it
+fn sweep_both_ways(grid: &[Vec<u8>], cells: &[(usize, usize)], trees: &mut [Vec<(bool, u64)>]) {
+    let line: Vec<u8> = cells.iter().map(|(x, y)| grid[*y][*x]).collect();
+    let mut reversed = line.clone();
+    reversed.reverse();
+
+    let forward = sweep(&line);
+    let backward = sweep(&reversed).into_iter().rev();
+    for ((x, y), ((visible_a, seen_a), (visible_b, seen_b))) in
+        cells.iter().zip(forward.into_iter().zip(backward))
+    {
+        let tree = &mut trees[*y][*x];
+        tree.0 |= visible_a || visible_b;
+        tree.1 *= seen_a * seen_b;
+    }
+}
+
+// The size is the width and height of the square grid of trees
+impl Generator for Day08 {
+    const INPUT_SIZE: usize = 99;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let size = size.max(1);
+        let heights: Vec<Vec<u8>> = (0..size)
+            .map(|_| (0..size).map(|_| rng.gen_range(0..=9)).collect())
+            .collect();
+
+        let mut grid = String::new();
+        for row in &heights {
+            grid.extend(row.iter().map(|v| (b'0' + v) as char));
+            grid.push('\n');
+        }
+
+        // Whether each tree is visible, and its scenic score so far
+        let mut trees = vec![vec![(false, 1); size]; size];
+        for i in 0..size {
+            let row: Vec<(usize, usize)> = (0..size).map(|x| (x, i)).collect();
+            sweep_both_ways(&heights, &row, &mut trees);
+            let column: Vec<(usize, usize)> = (0..size).map(|y| (i, y)).collect();
+            sweep_both_ways(&heights, &column, &mut trees);
+        }
+
+        let trees = trees.iter().flatten();
+        let visible = trees.clone().filter(|(visible, _)| *visible).count();
+        let highest = trees.map(|(_, score)| *score).max().unwrap_or(0);
+        return (grid, (Answer::from(visible), Answer::from(highest)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day08::generate(7, 50);
+        assert_eq!(input, Day08::generate(7, 50).0);
+
+        let grid = Day08::parse(&input).unwrap();
+        assert_eq!((grid.len(), grid[0].len()), (50, 50));
+
+        let (one, two) = answers;
+        assert_eq!(Day08::part_one(&grid), Ok(one));
+        assert_eq!(Day08::part_two(&grid), Ok(two));
+    }
+}
@@ -0,0 +1,300 @@
+//! Day 8: Treetop Tree House
+//!
+//! Counts the trees visible from outside a grid of tree heights, and finds the
+//! best scenic score from inside it.
+#![warn(missing_docs)]
+
+use common::{Answer, ParseError, Solution, SolveError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::{debug, trace};
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day08;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Tree heights, row by row
+pub type Grid = Vec<Vec<u32>>;
+
+// Column = x position
+// Row = y position
+fn get_value(grid: &Grid, column: usize, row: usize) -> Option<u32> {
+    let row_d = grid.get(row)?;
+    let value_d = row_d.get(column);
+    return value_d.copied();
+}
+
+fn is_edge(grid: &Grid, column: usize, row: usize) -> bool {
+    if row == 0 || column == 0 {
+        return true;
+    }
+
+    let up = get_value(grid, column, row - 1);
+    let down = get_value(grid, column, row + 1);
+    let left = get_value(grid, column - 1, row);
+    let right = get_value(grid, column + 1, row);
+
+    return up.is_none() || down.is_none() || left.is_none() || right.is_none();
+}
+
+/// Whether the tree at column and row can be seen from outside the grid
+pub fn check_if_visible(grid: &Grid, column: usize, row: usize) -> bool {
+    if is_edge(grid, column, row) {
+        return true;
+    }
+
+    let value = get_value(grid, column, row).unwrap();
+
+    let mut left_visible = true;
+    for x in 0..column {
+        let el = get_value(grid, x, row).unwrap();
+        if value <= el {
+            left_visible = false;
+            break;
+        }
+    }
+    let mut right_visible = true;
+    for x in column + 1..grid[row].len() {
+        let el = get_value(grid, x, row).unwrap();
+        if value <= el {
+            right_visible = false;
+            break;
+        }
+    }
+
+    let mut up_visible = true;
+    for y in 0..row {
+        let el = get_value(grid, column, y).unwrap();
+        if value <= el {
+            up_visible = false;
+            break;
+        }
+    }
+    let mut down_visible = true;
+    for y in row + 1..grid.len() {
+        let el = get_value(grid, column, y).unwrap();
+        if value <= el {
+            down_visible = false;
+            break;
+        }
+    }
+
+    return up_visible || down_visible || left_visible || right_visible;
+}
+
+/// How many trees can be seen from the tree at column and row in each
+/// direction, multiplied together. None if that doesn't fit in 64 bits
+pub fn get_scenic_score(grid: &Grid, column: usize, row: usize) -> Option<u64> {
+    let value = get_value(grid, column, row).unwrap();
+
+    let mut left_trees: u64 = 0;
+    for x in (0..column).rev() {
+        let el = get_value(grid, x, row).unwrap();
+        left_trees += 1;
+        if el >= value {
+            break;
+        }
+    }
+    let mut right_trees = 0;
+    for x in column + 1..grid[row].len() {
+        let el = get_value(grid, x, row).unwrap();
+        right_trees += 1;
+        if el >= value {
+            break;
+        }
+    }
+
+    let mut up_trees: u64 = 0;
+    for y in (0..row).rev() {
+        let el = get_value(grid, column, y).unwrap();
+        up_trees += 1;
+        if el >= value {
+            break;
+        }
+    }
+    let mut down_trees = 0;
+    for y in row + 1..grid.len() {
+        let el = get_value(grid, column, y).unwrap();
+        down_trees += 1;
+        if el >= value {
+            break;
+        }
+    }
+
+    return up_trees
+        .checked_mul(down_trees)?
+        .checked_mul(left_trees)?
+        .checked_mul(right_trees);
+}
+
+fn score_error() -> SolveError {
+    return SolveError::new("Expected every scenic score to fit in 64 bits");
+}
+
+/// How many trees can be seen from outside the grid
+pub fn get_total_visible_trees(grid: &Grid) -> u64 {
+    let mut visible_trees = 0;
+
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            if check_if_visible(grid, x, y) {
+                trace!(column = x, row = y, "visible");
+                visible_trees += 1;
+            }
+        }
+    }
+
+    return visible_trees;
+}
+
+/// The best scenic score of any tree
+pub fn get_highest_scenic_score(grid: &Grid) -> Result<u64, SolveError> {
+    let mut highest_score = 0;
+
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            let score = get_scenic_score(grid, x, y).ok_or_else(score_error)?;
+            if score > highest_score {
+                debug!(column = x, row = y, score, "best scenic score so far");
+                highest_score = score;
+            }
+        }
+    }
+
+    return Ok(highest_score);
+}
+
+/// Same as get_total_visible_trees, with the rows split between threads
+#[cfg(feature = "parallel")]
+pub fn get_total_visible_trees_parallel(grid: &Grid) -> u64 {
+    return (0..grid.len())
+        .into_par_iter()
+        .map(|y| {
+            let row = 0..grid[y].len();
+            row.filter(|x| check_if_visible(grid, *x, y)).count() as u64
+        })
+        .sum();
+}
+
+/// Same as get_highest_scenic_score, with the rows split between threads
+#[cfg(feature = "parallel")]
+pub fn get_highest_scenic_score_parallel(grid: &Grid) -> Result<u64, SolveError> {
+    return (0..grid.len())
+        .into_par_iter()
+        .map(|y| {
+            (0..grid[y].len()).try_fold(0, |highest, x| {
+                Some(u64::max(highest, get_scenic_score(grid, x, y)?))
+            })
+        })
+        .try_reduce(|| 0, |a, b| Some(a.max(b)))
+        .ok_or_else(score_error);
+}
+
+/// Parse the tree heights, every row being as wide as the first one
+pub fn parse_input(input: &str) -> Result<Grid, ParseError> {
+    let mut grid: Grid = Vec::new();
+
+    let lines = input.lines();
+
+    for (i, line) in lines.enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let mut row = Vec::new();
+        for (j, c) in line.char_indices() {
+            let Some(num) = c.to_digit(10) else {
+                let message = "Expected a tree height, from 0 to 9";
+                return Err(ParseError::at(i + 1, line, &line[j..], message));
+            };
+            row.push(num);
+        }
+        if let Some(first) = grid.first() {
+            if first.len() != row.len() {
+                let message = format!("Expected a row of {} trees", first.len());
+                return Err(ParseError::new(i + 1, 1, line, message));
+            }
+        }
+        grid.push(row);
+    }
+    return Ok(grid);
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        #[cfg(feature = "parallel")]
+        return Ok(get_total_visible_trees_parallel(input).into());
+        #[cfg(not(feature = "parallel"))]
+        return Ok(get_total_visible_trees(input).into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        #[cfg(feature = "parallel")]
+        return Ok(get_highest_scenic_score_parallel(input)?.into());
+        #[cfg(not(feature = "parallel"))]
+        return Ok(get_highest_scenic_score(input)?.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let grid = parse_input(EXAMPLE).unwrap();
+
+        let visible_trees = get_total_visible_trees(&grid);
+
+        assert_eq!(visible_trees, 21);
+    }
+    #[test]
+    fn part_two() {
+        let grid = parse_input(EXAMPLE).unwrap();
+
+        let highest_score = get_highest_scenic_score(&grid);
+
+        assert_eq!(highest_score, Ok(8));
+    }
+    #[test]
+    fn large_scenic_score() {
+        // The tree in the middle sees 260 trees each way, 260^4 in all
+        let mut rows = vec!["1".repeat(521); 521];
+        rows[260].replace_range(260..261, "9");
+        let grid = parse_input(&rows.join("\n")).unwrap();
+
+        assert_eq!(get_highest_scenic_score(&grid), Ok(260u64.pow(4)));
+        #[cfg(feature = "parallel")]
+        assert_eq!(get_highest_scenic_score_parallel(&grid), Ok(260u64.pow(4)));
+    }
+    #[test]
+    fn invalid_grid() {
+        let error = parse_input("30373\n25512\n65a32\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+
+        let error = parse_input("30373\n2551\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+    #[cfg(all(feature = "parallel", feature = "generate"))]
+    #[test]
+    fn parallel_matches_sequential() {
+        use common::Generator;
+        let grid = parse_input(&Day08::generate(1, Day08::INPUT_SIZE).0).unwrap();
+
+        let visible = get_total_visible_trees(&grid);
+        assert_eq!(get_total_visible_trees_parallel(&grid), visible);
+        let highest = get_highest_scenic_score(&grid);
+        assert_eq!(get_highest_scenic_score_parallel(&grid), highest);
+    }
+}
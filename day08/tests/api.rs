@@ -0,0 +1,13 @@
+use common::solution_on_example;
+use day08::*;
+
+solution_on_example!(Day08, 21, 8);
+
+#[test]
+fn trees_from_outside() {
+    let grid = day08::parse_input(EXAMPLE).unwrap();
+
+    assert!(check_if_visible(&grid, 1, 1));
+    assert!(!check_if_visible(&grid, 3, 1));
+    assert_eq!(get_scenic_score(&grid, 2, 3), Some(8));
+}
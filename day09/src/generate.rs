@@ -0,0 +1,69 @@
+use crate::Day09;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+const KNOTS: usize = 10;
+
+// The size is the number of motions of the head. A rope of ten knots follows
+// them as they're drawn: the second knot moves the same as the tail of a rope
+// of two, so both tails are tracked on it
+impl Generator for Day09 {
+    const INPUT_SIZE: usize = 2000;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut motions = String::new();
+
+        let mut knots = [(0i32, 0i32); KNOTS];
+        let mut short_tail = HashSet::from([(0, 0)]);
+        let mut long_tail = HashSet::from([(0, 0)]);
+        for _ in 0..size.max(1) {
+            let (direction, step) = match rng.gen_range(0..4) {
+                0 => ("R", (1, 0)),
+                1 => ("L", (-1, 0)),
+                2 => ("U", (0, 1)),
+                _ => ("D", (0, -1)),
+            };
+            let steps = rng.gen_range(1..=20);
+            motions.push_str(&format!("{} {}\n", direction, steps));
+
+            for _ in 0..steps {
+                knots[0] = (knots[0].0 + step.0, knots[0].1 + step.1);
+                for i in 1..KNOTS {
+                    let (x, y) = (knots[i - 1].0 - knots[i].0, knots[i - 1].1 - knots[i].1);
+                    if x.abs() > 1 || y.abs() > 1 {
+                        knots[i] = (knots[i].0 + x.signum(), knots[i].1 + y.signum());
+                    }
+                }
+                short_tail.insert(knots[1]);
+                long_tail.insert(knots[KNOTS - 1]);
+            }
+        }
+        let answers = (
+            Answer::from(short_tail.len()),
+            Answer::from(long_tail.len()),
+        );
+        return (motions, answers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day09::generate(7, 100);
+        assert_eq!(input, Day09::generate(7, 100).0);
+
+        let motions = Day09::parse(&input).unwrap();
+        assert_eq!(motions.len(), 100);
+
+        let (one, two) = answers;
+        assert_eq!(Day09::part_one(&motions), Ok(one));
+        assert_eq!(Day09::part_two(&motions), Ok(two));
+    }
+}
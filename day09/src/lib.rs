@@ -0,0 +1,332 @@
+//! Day 9: Rope Bridge
+//!
+//! Simulates a rope whose knots follow its head around a grid, counting the
+//! positions its tail visits.
+#![warn(missing_docs)]
+
+use common::{stream, Answer, ParseError, Solution, SolveError, StreamError, Streaming};
+use std::{collections::HashSet, io::BufRead};
+use tracing::{debug, trace};
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day09;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// A knot of the rope, at a position on the grid. Up is positive y
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Knot {
+    x: i32,
+    y: i32,
+}
+
+impl Knot {
+    /// A knot at column x and row y
+    pub fn new(x: i32, y: i32) -> Self {
+        Knot { x, y }
+    }
+
+    /// Column and row of the knot
+    pub fn position(&self) -> (i32, i32) {
+        return (self.x, self.y);
+    }
+
+    /// Whether the knots overlap or are next to each other, diagonals included
+    pub fn touches_knot(&self, knot: &Self) -> bool {
+        let rel_x = self.x.abs_diff(knot.x);
+        let rel_y = self.y.abs_diff(knot.y);
+
+        return rel_x <= 1 && rel_y <= 1;
+    }
+    fn move_rel(&mut self, x: i32, y: i32) {
+        self.x += x;
+        self.y += y;
+    }
+    /// If both knots are in the same column or row, move self to knot
+    /// If they are not in the same column or row, move self diagonally to knot
+    pub fn move_closer_to(&mut self, knot: &Self) {
+        let rel_x = knot.x - self.x;
+        let rel_y = knot.y - self.y;
+
+        if rel_x == 0 && rel_y.abs() > 1 {
+            self.y += if rel_y > 0 { 1 } else { -1 }
+        } else if rel_y == 0 && rel_x.abs() > 1 {
+            self.x += if rel_x > 0 { 1 } else { -1 }
+        } else if rel_y.abs() > 1 || rel_x.abs() > 1 {
+            self.x += if rel_x > 0 { 1 } else { -1 };
+            self.y += if rel_y > 0 { 1 } else { -1 }
+        }
+    }
+}
+
+/// Where the head moves, one step at a time
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    /// Positive x
+    Right,
+    /// Negative x
+    Left,
+    /// Positive y
+    Up,
+    /// Negative y
+    Down,
+}
+
+/// A line of the input, moving the head a number of steps in a direction
+#[derive(Clone, Copy, Debug)]
+pub struct Motion {
+    direction: Direction,
+    steps: i32,
+}
+
+impl Motion {
+    /// Move steps times in direction
+    pub fn new(direction: Direction, steps: i32) -> Self {
+        return Motion { direction, steps };
+    }
+}
+
+// Read the motions line by line, calling f with each one
+fn iterate_input<L: AsRef<str>, E: From<ParseError>>(
+    lines: impl Iterator<Item = Result<L, E>>,
+    mut f: impl FnMut(Motion),
+) -> Result<(), E> {
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+        if line.is_empty() {
+            continue;
+        }
+        let mut splitted = line.split_whitespace();
+        let end = &line[line.len()..];
+        let direction_str = splitted.next().unwrap_or(end);
+        let steps_str = splitted.next().unwrap_or(end);
+
+        let direction = match direction_str {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => {
+                let message = "Expected a direction, R, L, U or D";
+                return Err(ParseError::at(i + 1, line, direction_str, message).into());
+            }
+        };
+        let steps = steps_str
+            .parse::<i32>()
+            .ok()
+            .filter(|steps| *steps >= 0)
+            .ok_or_else(|| ParseError::at(i + 1, line, steps_str, "Expected a number of steps"))?;
+        if let Some(word) = splitted.next() {
+            return Err(ParseError::at(i + 1, line, word, "Expected the end of the motion").into());
+        }
+
+        f(Motion { direction, steps });
+    }
+    return Ok(());
+}
+
+/// Read every motion, like R 4
+pub fn parse_input(input: &str) -> Result<Vec<Motion>, ParseError> {
+    let mut motions = Vec::new();
+    iterate_input(input.lines().map(Ok), |motion| motions.push(motion))?;
+    return Ok(motions);
+}
+
+// Move the head one step, then let every knot follow the one before it
+fn step_rope(head: &mut Knot, rope: &mut [Knot], direction: Direction) {
+    match direction {
+        Direction::Right => head.move_rel(1, 0),
+        Direction::Left => head.move_rel(-1, 0),
+        Direction::Up => head.move_rel(0, 1),
+        Direction::Down => head.move_rel(0, -1),
+    }
+
+    let mut rope_head = *head;
+    for knot in rope {
+        if !knot.touches_knot(&rope_head) {
+            knot.move_closer_to(&rope_head);
+        }
+        rope_head = knot.to_owned();
+    }
+    trace!(head = ?head.position(), tail = ?rope_head.position(), "step");
+}
+
+/// Every position of the rope after each step, head first
+pub fn get_rope_steps(motions: &[Motion], length: u32) -> Vec<Vec<Knot>> {
+    let mut head = Knot::new(0, 0);
+    let mut rope = vec![Knot::new(0, 0); length as usize];
+    let mut steps = Vec::new();
+
+    for motion in motions {
+        for _ in 0..motion.steps {
+            step_rope(&mut head, &mut rope, motion.direction);
+            steps.push([vec![head], rope.clone()].concat());
+        }
+    }
+    return steps;
+}
+
+/// Positions visited by the tail of a rope with a single knot after its head
+pub fn simulate_rope(motions: &[Motion]) -> HashSet<Knot> {
+    return simulate_long_rope(motions, 1);
+}
+
+/// Positions visited by the tail of a rope with length knots after its head
+pub fn simulate_long_rope(motions: &[Motion], length: u32) -> HashSet<Knot> {
+    let mut set = HashSet::new();
+
+    let mut head = Knot::new(0, 0);
+    let mut rope = Vec::new();
+
+    if length == 0 {
+        panic!("Length must be greater than 0");
+    }
+
+    for _ in 0..length {
+        rope.push(Knot::new(0, 0));
+    }
+
+    for motion in motions {
+        for _ in 0..motion.steps {
+            step_rope(&mut head, &mut rope, motion.direction);
+            let rope_tail = rope.last().unwrap();
+            set.insert(rope_tail.to_owned());
+        }
+        debug!(
+            direction = ?motion.direction,
+            steps = motion.steps,
+            tail = ?rope.last().unwrap().position(),
+            visited = set.len(),
+            "motion"
+        );
+    }
+
+    return set;
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(simulate_rope(input).len().into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(simulate_long_rope(input, 9).len().into());
+    }
+}
+
+// Only the motion being made is kept, though the positions visited by both
+// tails still grow with how far they wander
+impl Streaming for Day09 {
+    fn solve_stream(reader: impl BufRead) -> Result<(Answer, Answer), StreamError> {
+        let mut short = (Knot::new(0, 0), vec![Knot::new(0, 0); 1], HashSet::new());
+        let mut long = (Knot::new(0, 0), vec![Knot::new(0, 0); 9], HashSet::new());
+
+        iterate_input(stream::lines(reader), |motion| {
+            for _ in 0..motion.steps {
+                for (head, rope, visited) in [&mut short, &mut long] {
+                    step_rope(head, rope, motion.direction);
+                    visited.insert(*rope.last().unwrap());
+                }
+            }
+        })?;
+
+        return Ok((short.2.len().into(), long.2.len().into()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        return prop_oneof![
+            Just(Direction::Right),
+            Just(Direction::Left),
+            Just(Direction::Up),
+            Just(Direction::Down),
+        ];
+    }
+
+    #[test]
+    fn part_one() {
+        let motions = parse_input(EXAMPLE).unwrap();
+        let set = simulate_rope(&motions);
+
+        assert_eq!(set.len(), 13);
+    }
+    #[test]
+    fn part_two() {
+        let motions = parse_input(EXAMPLE).unwrap();
+        let set = simulate_long_rope(&motions, 9);
+
+        assert_eq!(set.len(), 1);
+    }
+    #[test]
+    fn part_two_secondary() {
+        let motions = parse_input(include_str!("../test2.txt")).unwrap();
+        let set = simulate_long_rope(&motions, 9);
+
+        assert_eq!(set.len(), 36);
+    }
+    #[test]
+    fn invalid_motion() {
+        let error = parse_input("R 4\nX 4\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = parse_input("R 4\nU\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    proptest! {
+        #[test]
+        fn knots_touch_after_every_step(
+            directions in prop::collection::vec(direction(), 0..200),
+            length in 1usize..12,
+        ) {
+            let mut head = Knot::new(0, 0);
+            let mut rope = vec![Knot::new(0, 0); length];
+
+            for direction in directions {
+                let previous = rope.clone();
+                step_rope(&mut head, &mut rope, direction);
+
+                let mut rope_head = head;
+                for (knot, before) in rope.iter().zip(&previous) {
+                    prop_assert!(knot.touches_knot(&rope_head));
+                    // A knot moves at most one square in each axis per step
+                    prop_assert!(knot.touches_knot(before));
+                    rope_head = *knot;
+                }
+            }
+        }
+
+        #[test]
+        fn tail_visits_at_most_every_step(
+            motions in prop::collection::vec((direction(), 0i32..10), 0..30),
+            length in 1u32..12,
+        ) {
+            let motions: Vec<Motion> = motions
+                .into_iter()
+                .map(|(direction, steps)| Motion { direction, steps })
+                .collect();
+            let steps: i32 = motions.iter().map(|v| v.steps).sum();
+            let set = simulate_long_rope(&motions, length);
+
+            prop_assert!(set.len() <= steps as usize);
+        }
+    }
+    common::stream_matches_parse!(Day09);
+}
@@ -0,0 +1,20 @@
+use common::solution_on_example;
+use day09::*;
+
+solution_on_example!(Day09, 13, 1);
+
+#[test]
+fn knots_from_outside() {
+    let mut tail = Knot::new(0, 0);
+    let head = Knot::new(2, 1);
+
+    assert!(!tail.touches_knot(&head));
+    tail.move_closer_to(&head);
+    assert_eq!(tail.position(), (1, 1));
+
+    let motions = [
+        Motion::new(Direction::Right, 4),
+        Motion::new(Direction::Up, 4),
+    ];
+    assert_eq!(simulate_rope(&motions).len(), 7);
+}
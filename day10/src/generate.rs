@@ -0,0 +1,95 @@
+use crate::Day10;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const WIDTH: usize = 40;
+const FRAME: usize = WIDTH * 6;
+
+// The CPU as the program is written: the total signal strength, and the
+// last frame the CRT drew over
+struct Cpu {
+    cycle: usize,
+    signal: i64,
+    pixels: [u8; FRAME],
+}
+
+impl Cpu {
+    fn tick(&mut self, register: i32) {
+        let column = (self.cycle % WIDTH) as i32;
+        self.pixels[self.cycle % FRAME] = match (column - register).abs() <= 1 {
+            true => b'#',
+            false => b'.',
+        };
+        self.cycle += 1;
+        if self.cycle % WIDTH == 20 {
+            self.signal += self.cycle as i64 * register as i64;
+        }
+    }
+
+    fn image(&self) -> String {
+        let rows: Vec<&str> = self.pixels[..self.cycle.min(FRAME)]
+            .chunks(WIDTH)
+            .map(|row| std::str::from_utf8(row).unwrap())
+            .collect();
+        return rows.join("\n");
+    }
+}
+
+// The size is the number of instructions. The register stays within the
+// width of the screen, so the sprite is always somewhere to be drawn. The
+// program is run as it's written, one cycle at a time
+impl Generator for Day10 {
+    const INPUT_SIZE: usize = 140;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut program = String::new();
+        let mut register: i32 = 1;
+        let mut cpu = Cpu {
+            cycle: 0,
+            signal: 0,
+            pixels: [b'.'; FRAME],
+        };
+
+        for _ in 0..size.max(1) {
+            cpu.tick(register);
+            if rng.gen_bool(0.4) {
+                program.push_str("noop\n");
+                continue;
+            }
+            cpu.tick(register);
+            let value = rng.gen_range(-15..=15);
+            let value = match register + value {
+                0..=39 => value,
+                _ => -value,
+            };
+            register += value;
+            program.push_str(&format!("addx {}\n", value));
+        }
+        let answers = (Answer::from(cpu.signal), Answer::grid_from(&cpu.image()));
+        return (program, answers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let input = Day10::generate(7, 140).0;
+        assert_eq!(input, Day10::generate(7, 140).0);
+
+        // Less than a frame, and a program drawing over it a few times
+        for size in [140, 1000] {
+            let (input, (one, two)) = Day10::generate(7, size);
+            let instructions = Day10::parse(&input).unwrap();
+            assert_eq!(instructions.len(), size);
+
+            assert_eq!(Day10::part_one(&instructions), Ok(one));
+            assert_eq!(Day10::part_two(&instructions), Ok(two));
+        }
+    }
+}
@@ -0,0 +1,316 @@
+//! Day 10: Cathode-Ray Tube
+//!
+//! Runs a program on a CPU with a single register, adding up signal strengths
+//! and drawing the image it produces on a CRT.
+#![warn(missing_docs)]
+
+use common::{stream, Answer, ParseError, Solution, SolveError, StreamError, Streaming};
+use std::io::BufRead;
+use tracing::{debug, trace};
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day10;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// A CPU instruction
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    /// Do nothing for a cycle
+    Noop,
+    /// Add to the register after two cycles
+    Addx(i32),
+}
+
+// Read the instructions line by line, calling f with each one. The register
+// is followed along, so running the instructions can't overflow it
+fn parse_lines<L: AsRef<str>, E: From<ParseError>>(
+    lines: impl Iterator<Item = Result<L, E>>,
+    mut f: impl FnMut(Instruction),
+) -> Result<(), E> {
+    let mut register: i64 = 1;
+
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+        if line.is_empty() {
+            continue;
+        }
+        let mut splitted = line.split_whitespace();
+        let end = &line[line.len()..];
+        let call = splitted.next().unwrap_or(end);
+        let word = splitted.next();
+
+        match (call, word) {
+            ("noop", None) => f(Instruction::Noop),
+            ("addx", Some(word)) => {
+                let num: i32 = word
+                    .parse()
+                    .map_err(|_| ParseError::at(i + 1, line, word, "Expected a number"))?;
+                register = register.checked_add(num as i64).ok_or_else(|| {
+                    let message = "Expected the register to stay within 64 bits";
+                    ParseError::at(i + 1, line, word, message)
+                })?;
+                f(Instruction::Addx(num));
+            }
+            ("addx", None) => {
+                return Err(ParseError::at(i + 1, line, end, "Expected a number").into());
+            }
+            ("noop", Some(word)) => {
+                let message = "Expected the end of the instruction";
+                return Err(ParseError::at(i + 1, line, word, message).into());
+            }
+            _ => {
+                let message = "Expected an instruction, noop or addx";
+                return Err(ParseError::at(i + 1, line, call, message).into());
+            }
+        }
+        if let Some(word) = splitted.next() {
+            let message = "Expected the end of the instruction";
+            return Err(ParseError::at(i + 1, line, word, message).into());
+        }
+    }
+    return Ok(());
+}
+
+/// Read every instruction, noop or addx V
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+    parse_lines(input.lines().map(Ok), |instruction| {
+        instructions.push(instruction)
+    })?;
+    return Ok(instructions);
+}
+
+fn run_instruction(
+    instruction: &Instruction,
+    cycle: &mut i64,
+    register: &mut i64,
+    mut f: impl FnMut(&mut i64, i64),
+) {
+    let effort = match instruction {
+        Instruction::Noop => 1,
+        Instruction::Addx(_) => 2,
+    };
+
+    for _ in 0..effort {
+        f(cycle, *register);
+    }
+    if let Instruction::Addx(num) = instruction {
+        *register += *num as i64;
+    }
+    trace!(?instruction, register = *register, "ran");
+}
+
+fn iterate_input(instructions: &[Instruction], mut f: impl FnMut(&mut i64, i64)) {
+    let mut cycle = 0;
+    let mut register = 1;
+
+    for instruction in instructions {
+        run_instruction(instruction, &mut cycle, &mut register, &mut f);
+    }
+}
+
+// The signal strength during a cycle, counting from 1, if it's the 20th or
+// one of every 40 after it. None if it's too large to count
+fn get_signal_strength(cycle: i64, register: i64) -> Option<i64> {
+    let n = if cycle > 20 { cycle + 20 } else { cycle };
+    if n == 20 || n % 40 == 0 {
+        let strength = cycle.checked_mul(register)?;
+        debug!(cycle, register, strength, "signal");
+        return Some(strength);
+    }
+    return Some(0);
+}
+
+// Add a cycle's signal strength to the total, which stays None once it
+// couldn't be counted
+fn add_signal_strength(total: &mut Option<i64>, cycle: i64, register: i64) {
+    *total = total.and_then(|total| total.checked_add(get_signal_strength(cycle, register)?));
+}
+
+fn signal_error() -> SolveError {
+    return SolveError::new("Expected the sum of signal strengths to fit in 64 bits");
+}
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+// The pixels of the CRT. A program running for more than a frame starts
+// drawing over it again from the top left, so only the last frame is kept,
+// however long the program is
+struct Screen {
+    pixels: [u8; SCREEN_WIDTH * SCREEN_HEIGHT],
+    drawn: usize,
+}
+
+impl Screen {
+    fn new() -> Self {
+        return Screen {
+            pixels: [b'.'; SCREEN_WIDTH * SCREEN_HEIGHT],
+            drawn: 0,
+        };
+    }
+
+    // Draw the pixel of a cycle, counting from 0
+    fn draw(&mut self, cycle: i64, register: i64) {
+        let index = cycle.rem_euclid(self.pixels.len() as i64) as usize;
+        let column = (index % SCREEN_WIDTH) as i64;
+        self.pixels[index] = if column.abs_diff(register) <= 1 {
+            b'#'
+        } else {
+            b'.'
+        };
+        self.drawn = self.drawn.max(index + 1);
+    }
+
+    // The rows drawn so far, one per line
+    fn image(&self) -> String {
+        let rows: Vec<&str> = self.pixels[..self.drawn]
+            .chunks(SCREEN_WIDTH)
+            .map(|row| std::str::from_utf8(row).unwrap())
+            .collect();
+        return rows.join("\n");
+    }
+}
+
+/// The register during each cycle
+pub fn get_register_values(instructions: &[Instruction]) -> Vec<i64> {
+    let mut values = Vec::new();
+    iterate_input(instructions, |_, register| values.push(register));
+    return values;
+}
+
+/// Sum of the signal strengths during the 20th cycle and every 40 after it.
+/// Fails if it's too large to count
+pub fn get_sum_of_signal_strengths(instructions: &[Instruction]) -> Result<i64, SolveError> {
+    let mut total_signal = Some(0);
+
+    iterate_input(instructions, |cycle, register| {
+        *cycle += 1;
+        add_signal_strength(&mut total_signal, *cycle, register);
+    });
+
+    return total_signal.ok_or_else(signal_error);
+}
+
+/// The image on the CRT once the program ends, lit pixels as # and dark
+/// ones as ., at most 6 rows of 40
+pub fn draw_image(instructions: &[Instruction]) -> String {
+    let mut screen = Screen::new();
+
+    iterate_input(instructions, |cycle, register| {
+        screen.draw(*cycle, register);
+        *cycle += 1;
+    });
+
+    return screen.image();
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(get_sum_of_signal_strengths(input)?.into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(Answer::grid_from(&draw_image(input)));
+    }
+}
+
+// Only the last frame of the CRT is kept, so memory stays the same however
+// long the program is
+impl Streaming for Day10 {
+    fn solve_stream(reader: impl BufRead) -> Result<(Answer, Answer), StreamError> {
+        let mut cycle = 0;
+        let mut register = 1;
+        let mut total_signal = Some(0);
+        let mut screen = Screen::new();
+
+        parse_lines(stream::lines(reader), |instruction| {
+            run_instruction(
+                &instruction,
+                &mut cycle,
+                &mut register,
+                |cycle, register| {
+                    screen.draw(*cycle, register);
+                    *cycle += 1;
+                    add_signal_strength(&mut total_signal, *cycle, register);
+                },
+            );
+        })?;
+
+        let total_signal = total_signal.ok_or_else(signal_error)?;
+        return Ok((total_signal.into(), Answer::grid_from(&screen.image())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn part_one() {
+        let instructions = parse_input(EXAMPLE).unwrap();
+        let signal_strength = get_sum_of_signal_strengths(&instructions);
+
+        assert_eq!(signal_strength, Ok(13140));
+    }
+
+    #[test]
+    fn part_two() {
+        let test_image = include_str!("../test_image.txt").trim_end();
+
+        let instructions = parse_input(EXAMPLE).unwrap();
+        let image = draw_image(&instructions);
+
+        assert_eq!(test_image, image);
+    }
+
+    #[test]
+    fn last_frame() {
+        // A frame and a half, with the sprite moved right for the second one
+        let program = format!("{}addx 3\n{}", "noop\n".repeat(238), "noop\n".repeat(120));
+        let instructions = parse_input(&program).unwrap();
+        let image = draw_image(&instructions);
+
+        let second = format!("...###{}", ".".repeat(34));
+        let first = format!("###{}", ".".repeat(37));
+        let rows: Vec<&str> = image.lines().collect();
+        assert_eq!(rows.len(), 6);
+        assert!(rows[..3].iter().all(|row| *row == second));
+        assert!(rows[3..].iter().all(|row| *row == first));
+
+        let (_, streamed) = Day10::solve_stream(BufReader::new(program.as_bytes())).unwrap();
+        assert_eq!(streamed, Answer::grid_from(&image));
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let error = parse_input("noop\naddx 3\naddx -x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+
+        let error = parse_input("noop\nmulx 3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn signal_overflow() {
+        // Cycles times a register this high soon outgrow 64 bits
+        let instructions = parse_input(&format!("addx {}\n", i32::MAX).repeat(1 << 16)).unwrap();
+        assert!(get_sum_of_signal_strengths(&instructions).is_err());
+        assert!(Day10::part_one(&instructions).is_err());
+    }
+    common::stream_matches_parse!(Day10);
+}
@@ -0,0 +1,19 @@
+use common::{solution_on_example, Answer};
+use day10::*;
+
+solution_on_example!(
+    Day10,
+    13140,
+    Answer::grid_from(include_str!("../test_image.txt"))
+);
+
+#[test]
+fn register_from_outside() {
+    let instructions = [
+        Instruction::Noop,
+        Instruction::Addx(3),
+        Instruction::Addx(-5),
+    ];
+
+    assert_eq!(get_register_values(&instructions), vec![1, 1, 1, 4, 4]);
+}
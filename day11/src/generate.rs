@@ -0,0 +1,175 @@
+use crate::Day11;
+use common::{Answer, Generated, Generator};
+use rand::{seq::IteratorRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Divisors are reused past the ninth monkey, keeping their least common
+// multiple small enough to square a worry level without overflowing
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+// Notes drawn before giving up on multiplying worry levels
+const MAX_DRAWS: usize = 1000;
+
+#[derive(Clone)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+#[derive(Clone)]
+struct Notes {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    throw_true: usize,
+    throw_false: usize,
+}
+
+// Notes for every monkey. Tame ones only ever add to worry levels, which
+// relief then keeps low
+fn draw_notes(rng: &mut ChaCha8Rng, size: usize, tame: bool) -> Vec<Notes> {
+    let squaring = rng.gen_range(0..size);
+    return (0..size)
+        .map(|i| {
+            let items = (0..rng.gen_range(1..=8))
+                .map(|_| rng.gen_range(50..=99))
+                .collect();
+            let operation = match (i == squaring, rng.gen_bool(0.5)) {
+                _ if tame => Operation::Add(rng.gen_range(1..=8)),
+                (true, _) => Operation::Square,
+                (false, true) => Operation::Multiply(rng.gen_range(2..=19)),
+                (false, false) => Operation::Add(rng.gen_range(1..=8)),
+            };
+            let mut targets = (0..size).filter(|v| *v != i).choose_multiple(rng, 2);
+            let throw_true = targets.remove(0);
+            Notes {
+                items,
+                operation,
+                divisor: DIVISORS[i % DIVISORS.len()],
+                throw_true,
+                throw_false: targets.pop().unwrap_or(throw_true),
+            }
+        })
+        .collect();
+}
+
+// Keep the monkeys away for some rounds, relieving worry levels after each
+// inspection, and multiply how many items the two busiest ones inspected.
+// None if a worry level grows past what the solution counts in
+fn monkey_business(notes: &[Notes], rounds: usize, relief: impl Fn(u128) -> u128) -> Option<u64> {
+    let mut notes = notes.to_vec();
+    let mut inspected = vec![0u64; notes.len()];
+    for _ in 0..rounds {
+        for i in 0..notes.len() {
+            let items = std::mem::take(&mut notes[i].items);
+            inspected[i] += items.len() as u64;
+            for item in items {
+                let item = item as u128;
+                let item = relief(match notes[i].operation {
+                    Operation::Add(value) => item + value as u128,
+                    Operation::Multiply(value) => item * value as u128,
+                    Operation::Square => item * item,
+                });
+                if item > usize::MAX as u128 {
+                    return None;
+                }
+                let target = match (item as u64).is_multiple_of(notes[i].divisor) {
+                    true => notes[i].throw_true,
+                    false => notes[i].throw_false,
+                };
+                notes[target].items.push(item as u64);
+            }
+        }
+    }
+    inspected.sort_unstable_by(|a, b| b.cmp(a));
+    return Some(inspected[0] * inspected[1]);
+}
+
+fn write_notes(i: usize, notes: &Notes) -> String {
+    let items: Vec<String> = notes.items.iter().map(|v| v.to_string()).collect();
+    let operation = match notes.operation {
+        Operation::Add(value) => format!("+ {}", value),
+        Operation::Multiply(value) => format!("* {}", value),
+        Operation::Square => String::from("* old"),
+    };
+    return format!(
+        "Monkey {}:
+  Starting items: {}
+  Operation: new = old {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+        i,
+        items.join(", "),
+        operation,
+        notes.divisor,
+        notes.throw_true,
+        notes.throw_false
+    );
+}
+
+// The size is the number of monkeys. Like in the puzzle, only one of them
+// squares the worry level, and no monkey throws to itself. An item that
+// keeps coming back to the squaring monkey soon grows too worrying to count
+// without the modulo, so notes are drawn again until the first part can be
+// solved. With only a few monkeys that may never happen, so after enough
+// tries they're tame. The monkeys are then kept away to find the answers
+impl Generator for Day11 {
+    const INPUT_SIZE: usize = 8;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let size = size.max(2);
+
+        let (notes, first) = (0..)
+            .find_map(|draw| {
+                let notes = draw_notes(&mut rng, size, draw >= MAX_DRAWS);
+                let first = monkey_business(&notes, 20, |v| v / 3)?;
+                Some((notes, first))
+            })
+            .unwrap();
+        // The divisors are primes, so their least common multiple is the
+        // product of the ones in use
+        let used = &DIVISORS[..size.min(DIVISORS.len())];
+        let modulo = used.iter().map(|v| *v as u128).product::<u128>();
+        let second = monkey_business(&notes, 10000, |v| v % modulo).unwrap();
+
+        let notes: Vec<String> = notes
+            .iter()
+            .enumerate()
+            .map(|(i, v)| write_notes(i, v))
+            .collect();
+        return (
+            notes.join("\n"),
+            (Answer::from(first), Answer::from(second)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let input = Day11::generate(7, 20).0;
+        assert_eq!(input, Day11::generate(7, 20).0);
+
+        let monkeys = Day11::parse(&input).unwrap();
+        assert_eq!(monkeys.len(), 20);
+        assert_eq!(
+            crate::get_relief_modulo(&monkeys),
+            crate::Relief::Modulo(std::num::NonZeroUsize::new(223092870).unwrap())
+        );
+
+        for size in [0, 3, 8, 20] {
+            let (input, (one, two)) = Day11::generate(7, size);
+            let monkeys = Day11::parse(&input).unwrap();
+            assert_eq!(Day11::part_one(&monkeys), Ok(one));
+            assert_eq!(Day11::part_two(&monkeys), Ok(two));
+        }
+    }
+}
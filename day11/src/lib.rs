@@ -0,0 +1,385 @@
+//! Day 11: Monkey in the Middle
+//!
+//! Simulates monkeys throwing items between each other based on worry levels,
+//! and measures the resulting monkey business.
+#![warn(missing_docs)]
+
+use common::{Answer, ParseError, Solution, SolveError};
+use std::num::NonZeroUsize;
+use tracing::debug;
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day11;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Sum(usize),
+    Multiply(usize),
+    SumSelf,
+    MultiplySelf,
+}
+/// How worry levels are kept manageable after each inspection. Neither can
+/// be by 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief {
+    /// Divide by the value, as in part one
+    Divide(NonZeroUsize),
+    /// Keep worry levels modulo the value, see get_relief_modulo
+    Modulo(NonZeroUsize),
+}
+
+/// A monkey with the items it holds, and how it decides where to throw them
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    items_inspected: usize,
+    operation: Operation,
+    test: usize,
+    throw_true: usize,
+    throw_false: usize,
+}
+
+fn parse_number(line: usize, text: &str, string: &str) -> Result<usize, ParseError> {
+    return string
+        .parse::<usize>()
+        .map_err(|_| ParseError::at(line, text, string, "Expected a number"));
+}
+fn parse_items(line: usize, text: &str, string: &str) -> Result<Vec<usize>, ParseError> {
+    let mut items = Vec::new();
+
+    for v in string.split(", ") {
+        items.push(parse_number(line, text, v)?);
+    }
+    Ok(items)
+}
+fn parse_operation(line: usize, text: &str, string: &str) -> Result<Operation, ParseError> {
+    let operation_char = string.as_bytes().first();
+    let value_str = string.get(2..).unwrap_or(&string[string.len()..]);
+
+    return match (operation_char, value_str) {
+        (Some(b'*'), "old") => Ok(Operation::MultiplySelf),
+        (Some(b'+'), "old") => Ok(Operation::SumSelf),
+        (Some(b'*'), value) => Ok(Operation::Multiply(parse_number(line, text, value)?)),
+        (Some(b'+'), value) => Ok(Operation::Sum(parse_number(line, text, value)?)),
+        _ => Err(ParseError::at(
+            line,
+            text,
+            string,
+            "Expected an operation, + or *",
+        )),
+    };
+}
+
+/// Read the notes on every monkey, which must throw to other monkeys
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkey_list = Vec::new();
+    // Where each throw target was noted, checked once every monkey is known
+    let mut throws: Vec<(usize, usize, &str, &str)> = Vec::new();
+    let mut relief = 1;
+
+    let lines: Vec<&str> = input.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        if lines[i].trim().is_empty() {
+            i += 1;
+            continue;
+        }
+        let section_start = i;
+
+        let mut items: Option<Vec<usize>> = None;
+        let mut operation: Option<Operation> = None;
+        let mut test: Option<usize> = None;
+        let mut throw_true: Option<usize> = None;
+        let mut throw_false: Option<usize> = None;
+
+        while i < lines.len() && !lines[i].trim().is_empty() {
+            let line = lines[i];
+            let note = line.trim_start();
+
+            if let Some(items_str) = note.strip_prefix("Starting items: ") {
+                items = Some(parse_items(i + 1, line, items_str)?);
+            } else if let Some(operation_str) = note.strip_prefix("Operation: new = old ") {
+                operation = Some(parse_operation(i + 1, line, operation_str)?);
+            } else if let Some(divisible_str) = note.strip_prefix("Test: divisible by ") {
+                let divisor = parse_number(i + 1, line, divisible_str)?;
+                if divisor == 0 {
+                    let message = "Expected a divisor greater than 0";
+                    return Err(ParseError::at(i + 1, line, divisible_str, message));
+                }
+                // Part two keeps worry levels modulo every divisor at once
+                relief = match lcm(relief, divisor) {
+                    Some(value) => value,
+                    None => {
+                        let message =
+                            "Expected the divisors to have a common multiple that fits in a usize";
+                        return Err(ParseError::at(i + 1, line, divisible_str, message));
+                    }
+                };
+                test = Some(divisor);
+            } else if let Some(true_str) = note.strip_prefix("If true: throw to monkey ") {
+                throw_true = Some(parse_number(i + 1, line, true_str)?);
+                throws.push((throw_true.unwrap(), i + 1, line, true_str));
+            } else if let Some(false_str) = note.strip_prefix("If false: throw to monkey ") {
+                throw_false = Some(parse_number(i + 1, line, false_str)?);
+                throws.push((throw_false.unwrap(), i + 1, line, false_str));
+            } else if !note.starts_with("Monkey ") {
+                return Err(ParseError::at(i + 1, line, note, "Expected a monkey note"));
+            }
+            i += 1;
+        }
+
+        let missing = match (&items, &operation, test, throw_true, throw_false) {
+            (None, _, _, _, _) => "starting items",
+            (_, None, _, _, _) => "an operation",
+            (_, _, None, _, _) => "a test",
+            (_, _, _, None, _) => "a monkey to throw to if true",
+            (_, _, _, _, None) => "a monkey to throw to if false",
+            _ => "",
+        };
+        if !missing.is_empty() {
+            let message = format!("Expected the monkey to have {}", missing);
+            return Err(ParseError::new(
+                section_start + 1,
+                1,
+                lines[section_start],
+                message,
+            ));
+        }
+
+        let monkey = Monkey {
+            items: items.unwrap(),
+            items_inspected: 0,
+            operation: operation.unwrap(),
+            test: test.unwrap(),
+            throw_true: throw_true.unwrap(),
+            throw_false: throw_false.unwrap(),
+        };
+        monkey_list.push(monkey);
+    }
+
+    for (target, line, text, token) in throws {
+        if target >= monkey_list.len() {
+            let message = format!("Expected a monkey from 0 to {}", monkey_list.len() - 1);
+            return Err(ParseError::at(line, text, token, message));
+        }
+    }
+
+    // Monkey business is about the two most active monkeys
+    if monkey_list.len() < 2 {
+        let line = lines.len().max(1);
+        let text = lines.last().copied().unwrap_or("");
+        return Err(ParseError::new(
+            line,
+            1,
+            text,
+            "Expected at least two monkeys",
+        ));
+    }
+
+    Ok(monkey_list)
+}
+
+// The least common multiple of two numbers, none if it doesn't fit
+fn lcm(a: usize, b: usize) -> Option<usize> {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    return (a / x).checked_mul(b);
+}
+
+fn run_monkey_round(
+    monkey: Monkey,
+    monkey_list: &mut [Monkey],
+    relief: Relief,
+) -> Result<(), SolveError> {
+    for item in monkey.items.iter() {
+        // Worked out in 128 bits, where two usizes can't overflow, so only
+        // a worry level that stays too large once relieved fails
+        let item = *item as u128;
+        let item_value = match monkey.operation {
+            Operation::Sum(value) => item + value as u128,
+            Operation::Multiply(value) => item * value as u128,
+            Operation::SumSelf => item + item,
+            Operation::MultiplySelf => item * item,
+        };
+        let item_value = match relief {
+            Relief::Divide(value) => item_value / value.get() as u128,
+            Relief::Modulo(value) => item_value % value.get() as u128,
+        };
+        let item_value = usize::try_from(item_value).map_err(|_| {
+            SolveError::new(format!(
+                "Expected worry levels to fit in a usize, got {}",
+                item_value
+            ))
+        })?;
+        let monkey_to_throw = match item_value % monkey.test == 0 {
+            true => monkey_list.get_mut(monkey.throw_true).unwrap(),
+            false => monkey_list.get_mut(monkey.throw_false).unwrap(),
+        };
+        monkey_to_throw.items.push(item_value);
+    }
+    return Ok(());
+}
+
+/// The monkeys after rounds rounds, counting every item each one inspects.
+/// Fails if a worry level grows too large to count
+pub fn run_monkey_list_rounds(
+    monkey_list: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+) -> Result<Vec<Monkey>, SolveError> {
+    let mut new_monkey_list = monkey_list.to_vec();
+    for round in 0..rounds {
+        for i in 0..monkey_list.len() {
+            if let Some(monkey) = new_monkey_list.get_mut(i) {
+                run_monkey_round(monkey.clone(), &mut new_monkey_list, relief)?;
+            }
+            if let Some(monkey) = new_monkey_list.get_mut(i) {
+                monkey.items_inspected += monkey.items.len();
+                monkey.items.clear();
+            }
+        }
+        // Like the puzzle's own walkthrough, the items each monkey has
+        // inspected so far
+        debug!(
+            round = round + 1,
+            inspected = ?new_monkey_list.iter().map(|v| v.items_inspected).collect::<Vec<usize>>(),
+            "after round"
+        );
+    }
+    return Ok(new_monkey_list);
+}
+
+/// Every divisibility test still holds if worry levels are kept modulo the
+/// least common multiple of all the monkeys' divisors. parse_input only
+/// accepts notes where it fits
+pub fn get_relief_modulo(monkey_list: &[Monkey]) -> Relief {
+    let relief = monkey_list
+        .iter()
+        .try_fold(1, |relief, monkey| lcm(relief, monkey.test))
+        .and_then(NonZeroUsize::new)
+        .expect("Expected the divisors' common multiple to be checked when parsing");
+    Relief::Modulo(relief)
+}
+
+/// Items inspected by the two most active monkeys, multiplied together.
+/// Fails if there's too many to count
+pub fn get_monkey_business(monkey_list: &[Monkey]) -> Result<usize, SolveError> {
+    let mut sorted_list = monkey_list.to_vec();
+    sorted_list.sort_by_key(|v| std::cmp::Reverse(v.items_inspected));
+    let (first, second) = (
+        sorted_list[0].items_inspected,
+        sorted_list[1].items_inspected,
+    );
+    return first.checked_mul(second).ok_or_else(|| {
+        SolveError::new(format!(
+            "Expected the monkey business of {} and {} inspected items to fit in a usize",
+            first, second
+        ))
+    });
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        let monkey_list =
+            run_monkey_list_rounds(input, 20, Relief::Divide(NonZeroUsize::new(3).unwrap()))?;
+        return Ok(get_monkey_business(&monkey_list)?.into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        let monkey_list = run_monkey_list_rounds(input, 10000, get_relief_modulo(input))?;
+        return Ok(get_monkey_business(&monkey_list)?.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let monkey_list = parse_input(EXAMPLE).unwrap();
+        let new_monkey_list = run_monkey_list_rounds(
+            &monkey_list,
+            20,
+            Relief::Divide(NonZeroUsize::new(3).unwrap()),
+        )
+        .unwrap();
+
+        assert_eq!(get_monkey_business(&new_monkey_list), Ok(10605));
+    }
+
+    #[test]
+    fn part_two() {
+        let monkey_list = parse_input(EXAMPLE).unwrap();
+
+        let mut relief: usize = 1;
+        monkey_list.iter().for_each(|v| relief *= v.test);
+        let relief = Relief::Modulo(NonZeroUsize::new(relief).unwrap());
+        let new_monkey_list = run_monkey_list_rounds(&monkey_list, 10000, relief).unwrap();
+
+        assert_eq!(get_monkey_business(&new_monkey_list), Ok(2713310158));
+    }
+
+    #[test]
+    fn invalid_notes() {
+        let notes = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 0
+";
+        let error = parse_input(notes).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 30));
+
+        let error = parse_input(&notes.replace("* 19", "/ 19")).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 24));
+
+        let error = parse_input(&notes.replace("79, 98", "79, x")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 23));
+
+        let error = parse_input(&notes.replace("  Test: divisible by 23\n", "")).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+
+        let error = parse_input(&notes.replace("monkey 1", "monkey 0")).unwrap_err();
+        assert_eq!(error.message, "Expected at least two monkeys");
+    }
+
+    #[test]
+    fn worry_overflow() {
+        let huge = usize::MAX / 2 + 1;
+        let notes = EXAMPLE.replace("divisible by 23", &format!("divisible by {}", huge));
+        let error = parse_input(&notes.replace("divisible by 19", "divisible by 3")).unwrap_err();
+        assert_eq!((error.line, error.column), (11, 22));
+
+        // Squared, the starting items soon outgrow a usize before relief
+        let notes = EXAMPLE.replace(
+            "Starting items: 79, 98",
+            &format!("Starting items: {}", huge),
+        );
+        let monkey_list = parse_input(&notes).unwrap();
+        assert!(run_monkey_list_rounds(
+            &monkey_list,
+            20,
+            Relief::Divide(NonZeroUsize::new(3).unwrap())
+        )
+        .is_err());
+        assert!(Day11::part_one(&monkey_list).is_err());
+    }
+}
@@ -0,0 +1,14 @@
+use common::solution_on_example;
+use day11::*;
+use std::num::NonZeroUsize;
+
+solution_on_example!(Day11, 10605, 2713310158u64);
+
+#[test]
+fn monkeys_from_outside() {
+    let monkeys = day11::parse_input(EXAMPLE).unwrap();
+    let after = run_monkey_list_rounds(&monkeys, 20, Relief::Divide(NonZeroUsize::new(3).unwrap()))
+        .unwrap();
+
+    assert_eq!(get_monkey_business(&after), Ok(10605));
+}
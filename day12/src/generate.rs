@@ -0,0 +1,118 @@
+use crate::Day12;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::VecDeque;
+
+// A path climbing from a to z needs at least this many squares
+const MIN_WIDTH: usize = 26;
+
+// Steps from every square to the end, walking back from it one step at a
+// time, to squares at most one lower. None for squares that can't reach it
+fn steps_to_end(grid: &[Vec<u8>], end: (usize, usize)) -> Vec<Vec<Option<u64>>> {
+    let mut steps = vec![vec![None; grid[0].len()]; grid.len()];
+    steps[end.0][end.1] = Some(0);
+    let mut queue = VecDeque::from([end]);
+    while let Some((row, column)) = queue.pop_front() {
+        let next = steps[row][column].map(|v| v + 1);
+        let neighbours = [
+            (row.wrapping_sub(1), column),
+            (row + 1, column),
+            (row, column.wrapping_sub(1)),
+            (row, column + 1),
+        ];
+        for (r, c) in neighbours {
+            let Some(height) = grid.get(r).and_then(|v| v.get(c)) else {
+                continue;
+            };
+            if steps[r][c].is_none() && grid[row][column] <= height + 1 {
+                steps[r][c] = next;
+                queue.push_back((r, c));
+            }
+        }
+    }
+    return steps;
+}
+
+// The size is the width of the heightmap, which is a bit over a third as
+// tall as it is wide. A path winds from S on the left edge to E on the
+// right one, climbing one step at most at a time, so E can always be
+// reached. Every other square is random, and the answers are found by
+// walking back from E
+impl Generator for Day12 {
+    const INPUT_SIZE: usize = 113;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let width = size.max(MIN_WIDTH);
+        let height = (width * 41 / 113).max(5);
+
+        let mut grid: Vec<Vec<u8>> = (0..height)
+            .map(|_| (0..width).map(|_| rng.gen_range(b'a'..=b'z')).collect())
+            .collect();
+
+        let mut row = rng.gen_range(0..height);
+        let mut path = Vec::new();
+        for column in 0..width {
+            let target = match rng.gen_bool(0.3) {
+                true => rng.gen_range(0..height),
+                false => row,
+            };
+            while row != target {
+                path.push((row, column));
+                row = if target > row { row + 1 } else { row - 1 };
+            }
+            path.push((row, column));
+        }
+
+        let last = path.len() - 1;
+        for (i, (row, column)) in path.iter().enumerate() {
+            grid[*row][*column] = b'a' + (i * 25 / last) as u8;
+        }
+        let (start_row, start_column) = path[0];
+        let (end_row, end_column) = path[last];
+
+        let steps = steps_to_end(&grid, path[last]);
+        let from_start = steps[start_row][start_column].unwrap();
+        let from_lowest = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .filter(|(row, column)| grid[*row][*column] == b'a')
+            .filter_map(|(row, column)| steps[row][column])
+            .min()
+            .unwrap();
+
+        grid[start_row][start_column] = b'S';
+        grid[end_row][end_column] = b'E';
+
+        let mut heightmap = String::new();
+        for line in grid {
+            heightmap.push_str(&String::from_utf8(line).unwrap());
+            heightmap.push('\n');
+        }
+        let answers = (Answer::from(from_start), Answer::from(from_lowest));
+        return (heightmap, answers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day12::generate(7, 30);
+        assert_eq!(input, Day12::generate(7, 30).0);
+
+        let heightmap = Day12::parse(&input).unwrap();
+        match Day12::part_one(&heightmap).unwrap() {
+            // At least the width of the map away, and never unreachable
+            Answer::Integer(steps) => assert!((29..300).contains(&steps)),
+            answer => panic!("Expected a number of steps, got {}", answer),
+        }
+
+        let (one, two) = answers;
+        assert_eq!(Day12::part_one(&heightmap), Ok(one));
+        assert_eq!(Day12::part_two(&heightmap), Ok(two));
+    }
+}
@@ -0,0 +1,333 @@
+//! Day 12: Hill Climbing Algorithm
+//!
+//! Finds the fewest steps up a heightmap to the best signal, from the start and
+//! from any square at the lowest elevation.
+#![warn(missing_docs)]
+
+use common::{Answer, ParseError, Solution, SolveError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap},
+    fmt::Display,
+};
+use tracing::{debug, debug_span, trace, warn};
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day12;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// A square of the heightmap
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct Node {
+    value: char,
+    x: usize,
+    y: usize,
+}
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{value: {}({}) (x: {}, y: {})}}",
+            self.value, self.value as u32, self.x, self.y
+        )
+    }
+}
+
+impl Node {
+    /// Column and row in the heightmap
+    pub fn position(&self) -> (usize, usize) {
+        return (self.x, self.y);
+    }
+    /// Elevation from a to z. The start is at a and the best signal at z
+    pub fn elevation(&self) -> char {
+        return self.value;
+    }
+}
+
+fn get_four_sides(graph: &[Node], node: &Node) -> Vec<Node> {
+    let mut list = Vec::new();
+
+    for u in graph.iter() {
+        if list.len() >= 4 {
+            break;
+        }
+
+        let right_pos = (node.x + 1, node.y);
+        let bottom_pos = (node.x, node.y + 1);
+
+        if u.x == right_pos.0 && u.y == right_pos.1 {
+            list.push(u.clone());
+        }
+        if u.x == bottom_pos.0 && u.y == bottom_pos.1 {
+            list.push(u.clone());
+        }
+        if node.x != 0 {
+            let left_pos = (node.x - 1, node.y);
+            if u.x == left_pos.0 && u.y == left_pos.1 {
+                list.push(u.clone());
+            }
+        }
+        if node.y != 0 {
+            let top_pos = (node.x, node.y - 1);
+            if u.x == top_pos.0 && u.y == top_pos.1 {
+                list.push(u.clone());
+            }
+        }
+    }
+
+    return list;
+}
+
+/// Steps from initial back to the source of a pathfind, or 0 if it can't be
+/// reached
+pub fn get_path_steps(previous: &BTreeMap<Node, Node>, initial: &Node) -> u32 {
+    let mut steps = 0;
+    if previous.contains_key(initial) {
+        let mut opt = previous.get(initial);
+        while let Some(u) = opt {
+            trace!(node = %u, "step back");
+            opt = previous.get(u);
+            steps += 1;
+            if steps > 1000000 {
+                warn!(from = %initial, steps, "path is over a million steps long, giving up on it");
+                break;
+            }
+        }
+    }
+    return steps;
+}
+
+/// Minimum steps from any 'a' elevation, given a reversed pathfind from the end
+pub fn get_min_steps_from_lowest(previous: &BTreeMap<Node, Node>, source: &Node) -> u32 {
+    let mut steps = get_path_steps(previous, source);
+    for key in previous.keys() {
+        if key.value != 'a' {
+            continue;
+        }
+        let s = get_path_steps(previous, key);
+        if s < steps {
+            steps = s;
+        }
+    }
+    return steps;
+}
+
+/// Same as get_min_steps_from_lowest, with the 'a' elevations split between
+/// threads
+#[cfg(feature = "parallel")]
+pub fn get_min_steps_from_lowest_parallel(previous: &BTreeMap<Node, Node>, source: &Node) -> u32 {
+    let lowest: Vec<&Node> = previous.keys().filter(|key| key.value == 'a').collect();
+    let steps = lowest
+        .par_iter()
+        .map(|key| get_path_steps(previous, key))
+        .min();
+    let source_steps = get_path_steps(previous, source);
+    return steps.map_or(source_steps, |steps| steps.min(source_steps));
+}
+
+/// Shortest paths from source, as the node before each reachable node. A
+/// step can climb at most one elevation, or drop at most one when reversed
+pub fn dijkstra_pathfind(graph: &[Node], source: &Node, reverse: bool) -> BTreeMap<Node, Node> {
+    return dijkstra_pathfind_visiting(graph, source, reverse, |_| {});
+}
+
+/// Same as dijkstra_pathfind, calling visit with every node as it's reached
+pub fn dijkstra_pathfind_visiting(
+    graph: &[Node],
+    source: &Node,
+    reverse: bool,
+    mut visit: impl FnMut(&Node),
+) -> BTreeMap<Node, Node> {
+    let _pathfind = debug_span!("pathfind", source = %source, reverse).entered();
+    let mut previous: BTreeMap<Node, Node> = BTreeMap::new();
+    let mut distances: BTreeMap<Node, u32> = BTreeMap::new();
+
+    // Nodes still to visit, nearest first. A node is pushed again whenever
+    // it's reached, so the ones already visited are skipped as they come up
+    let mut to_visit: BinaryHeap<Reverse<(u32, Node)>> = BinaryHeap::new();
+    let mut visited: BTreeSet<Node> = BTreeSet::new();
+
+    for vertex in graph.iter() {
+        distances.insert(vertex.clone(), u32::MAX);
+    }
+    distances.insert(source.clone(), 0);
+    to_visit.push(Reverse((0, source.clone())));
+
+    while let Some(Reverse((distance, u))) = to_visit.pop() {
+        if !visited.insert(u.clone()) {
+            continue;
+        }
+        visit(&u);
+        trace!(element = %u, distance, "visiting");
+
+        let four_sides = get_four_sides(graph, &u);
+
+        for neighbour in four_sides.iter() {
+            let v = neighbour;
+
+            let u_value = u.value as u32;
+            let v_value = v.value as u32;
+
+            let range = if reverse {
+                u_value - 1..=u32::MAX
+            } else {
+                0..=u_value + 1
+            };
+
+            if !range.contains(&v_value) {
+                trace!(neighbour = %v, ?range, "out of reach");
+                continue;
+            }
+
+            let v_distance = *distances.get(v).unwrap();
+
+            let alt = distance + 1;
+            if alt <= v_distance && !visited.contains(v) {
+                previous.insert(v.clone(), u.clone());
+                distances.insert(v.clone(), alt);
+                to_visit.push(Reverse((alt, v.clone())));
+                trace!(neighbour = %v, distance = alt, "reached");
+            }
+        }
+    }
+    debug!(reached = previous.len(), "pathfind done");
+
+    return previous;
+}
+
+/// Read the heightmap, along with the start and the best signal squares
+pub fn parse_input(input: &str) -> Result<(Vec<Node>, Node, Node), ParseError> {
+    let mut height_map: Vec<Node> = Vec::new();
+    let mut source: Option<Node> = None;
+    let mut end: Option<Node> = None;
+
+    for (i, line) in input.lines().enumerate() {
+        for (j, char) in line.char_indices() {
+            let mut node = Node {
+                value: char,
+                x: j,
+                y: i,
+            };
+            let error = |message| ParseError::at(i + 1, line, &line[j..], message);
+
+            if char == 'S' {
+                if source.is_some() {
+                    return Err(error("Expected a single starting position"));
+                }
+                node.value = 'a';
+                source = Some(node.clone());
+            } else if char == 'E' {
+                if end.is_some() {
+                    return Err(error("Expected a single best signal position"));
+                }
+                node.value = 'z';
+                end = Some(node.clone());
+            } else if !char.is_ascii_lowercase() {
+                return Err(error("Expected an elevation, from a to z, S or E"));
+            }
+            height_map.push(node);
+        }
+    }
+
+    let first_line = input.lines().next().unwrap_or("");
+    let Some(source) = source else {
+        return Err(ParseError::new(
+            1,
+            1,
+            first_line,
+            "Expected a starting position, S",
+        ));
+    };
+    let Some(end) = end else {
+        let message = "Expected a best signal position, E";
+        return Err(ParseError::new(1, 1, first_line, message));
+    };
+
+    return Ok((height_map, source, end));
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = (Vec<Node>, Node, Node);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        let (height_map, source, end) = input;
+        let previous = dijkstra_pathfind(height_map, source, false);
+        return Ok(get_path_steps(&previous, end).into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        let (height_map, source, end) = input;
+        let previous = dijkstra_pathfind(height_map, end, true);
+        #[cfg(feature = "parallel")]
+        return Ok(get_min_steps_from_lowest_parallel(&previous, source).into());
+        #[cfg(not(feature = "parallel"))]
+        return Ok(get_min_steps_from_lowest(&previous, source).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let (height_map, source, end) = parse_input(EXAMPLE).unwrap();
+        let previous = dijkstra_pathfind(&height_map, &source, false);
+        let steps = get_path_steps(&previous, &end);
+
+        assert_eq!(steps, 31);
+    }
+
+    #[test]
+    fn part_two() {
+        let (height_map, source, end) = parse_input(EXAMPLE).unwrap();
+        let previous = dijkstra_pathfind(&height_map, &end, true);
+
+        let mut steps = get_path_steps(&previous, &source);
+        for key in previous.keys() {
+            if key.value != 'a' {
+                continue;
+            }
+            let s = get_path_steps(&previous, key);
+            if s < steps {
+                steps = s;
+            }
+        }
+        assert_eq!(steps, 29);
+    }
+
+    #[test]
+    fn invalid_heightmap() {
+        let error = parse_input("Sabqponm\nabcryxxl\naccszE1k\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 7));
+
+        let error = parse_input("Sabqponm\nabcryxxl\n").unwrap_err();
+        assert_eq!(error.message, "Expected a best signal position, E");
+    }
+    #[cfg(all(feature = "parallel", feature = "generate"))]
+    #[test]
+    fn parallel_matches_sequential() {
+        use common::Generator;
+        let input = Day12::generate(1, Day12::INPUT_SIZE).0;
+        let (height_map, source, end) = parse_input(&input).unwrap();
+        let previous = dijkstra_pathfind(&height_map, &end, true);
+
+        let steps = get_min_steps_from_lowest(&previous, &source);
+        assert_eq!(
+            get_min_steps_from_lowest_parallel(&previous, &source),
+            steps
+        );
+    }
+}
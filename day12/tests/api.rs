@@ -0,0 +1,15 @@
+use common::solution_on_example;
+use day12::*;
+
+solution_on_example!(Day12, 31, 29);
+
+#[test]
+fn pathfind_from_outside() {
+    let (height_map, source, end) = day12::parse_input(EXAMPLE).unwrap();
+    let previous = dijkstra_pathfind(&height_map, &source, false);
+
+    assert_eq!(get_path_steps(&previous, &end), 31);
+    assert_eq!(source.position(), (0, 0));
+    assert_eq!(source.elevation(), 'a');
+    assert_eq!(end.elevation(), 'z');
+}
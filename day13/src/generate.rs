@@ -0,0 +1,109 @@
+use crate::Day13;
+use common::{Answer, Generated, Generator};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn render(&self) -> String {
+        return match self {
+            Packet::Number(value) => value.to_string(),
+            Packet::List(items) => {
+                let items: Vec<String> = items.iter().map(Packet::render).collect();
+                format!("[{}]", items.join(","))
+            }
+        };
+    }
+
+    // The ordering from the puzzle description, a number against a list
+    // being compared as a list of just that number
+    fn compare(&self, other: &Packet) -> Ordering {
+        return match (self, other) {
+            (Packet::Number(a), Packet::Number(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| a.compare(b))
+                .find(|v| v.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len())),
+            (Packet::Number(_), list) => Packet::List(vec![self.clone()]).compare(list),
+            (list, Packet::Number(_)) => list.compare(&Packet::List(vec![other.clone()])),
+        };
+    }
+}
+
+fn list(rng: &mut ChaCha8Rng, depth: u32) -> Packet {
+    let values = (0..rng.gen_range(0..=5))
+        .map(|_| match depth < 4 && rng.gen_bool(0.3) {
+            true => list(rng, depth + 1),
+            false => Packet::Number(rng.gen_range(0..=10)),
+        })
+        .collect();
+    return Packet::List(values);
+}
+
+fn divider(value: u32) -> Packet {
+    return Packet::List(vec![Packet::List(vec![Packet::Number(value)])]);
+}
+
+// The size is the number of pairs of packets. Each pair is compared as it's
+// drawn, and each packet against the dividers. Sorting keeps packets equal
+// to a divider before it, as the dividers are added last
+impl Generator for Day13 {
+    const INPUT_SIZE: usize = 150;
+
+    fn generate(seed: u64, size: usize) -> Generated {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut pairs = Vec::new();
+        let mut right_order = 0u64;
+        let dividers = [divider(2), divider(6)];
+        // Where each divider ends up, counting from 1
+        let mut positions = [1u64, 2];
+
+        for i in 0..size.max(1) {
+            let left = list(&mut rng, 0);
+            let right = list(&mut rng, 0);
+            if left.compare(&right).is_lt() {
+                right_order += i as u64 + 1;
+            }
+            for packet in [&left, &right] {
+                for (divider, position) in dividers.iter().zip(&mut positions) {
+                    if packet.compare(divider).is_le() {
+                        *position += 1;
+                    }
+                }
+            }
+            pairs.push(format!("{}\n{}\n", left.render(), right.render()));
+        }
+        let answers = (
+            Answer::from(right_order),
+            Answer::from(positions[0] * positions[1]),
+        );
+        return (pairs.join("\n"), answers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn generated_input() {
+        let (input, answers) = Day13::generate(7, 100);
+        assert_eq!(input, Day13::generate(7, 100).0);
+
+        let pairs = Day13::parse(&input).unwrap();
+        assert_eq!(pairs.len(), 100);
+
+        let (one, two) = answers;
+        assert_eq!(Day13::part_one(&pairs), Ok(one));
+        assert_eq!(Day13::part_two(&pairs), Ok(two));
+    }
+}
@@ -0,0 +1,436 @@
+//! Day 13: Distress Signal
+//!
+//! Compares pairs of nested list packets, and sorts them all to find the
+//! decoder key.
+#![warn(missing_docs)]
+
+use common::{Answer, ParseError, Solution, SolveError};
+use std::cmp::Ordering;
+use tracing::debug;
+
+#[cfg(feature = "generate")]
+mod generate;
+
+/// The puzzle as a [`Solution`], for the runner and benchmarks
+pub struct Day13;
+
+/// The example from the puzzle description
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+#[derive(Debug)]
+enum PacketValue {
+    Number(u32),
+    NoNumber,
+    StartList,
+    EndList,
+}
+
+fn word_to_packet_number(word: &str) -> Option<PacketValue> {
+    let value = word.parse::<u32>();
+    if let Ok(value) = value {
+        return Some(PacketValue::Number(value));
+    }
+    return Some(PacketValue::NoNumber);
+}
+
+fn get_packet_value(packet: &str, position: &mut usize) -> Option<PacketValue> {
+    let mut word = String::new();
+    loop {
+        let char = packet.chars().nth(*position);
+        *position += 1;
+        match char {
+            Some('[') => {
+                if !word.is_empty() {
+                    *position -= 1;
+                    return word_to_packet_number(&word);
+                }
+                return Some(PacketValue::StartList);
+            }
+            Some(']') => {
+                if !word.is_empty() {
+                    *position -= 1;
+                    return word_to_packet_number(&word);
+                }
+                return Some(PacketValue::EndList);
+            }
+            Some(',') => {
+                return word_to_packet_number(&word);
+            }
+            Some(value) => {
+                word.push(value);
+            }
+            None => return None,
+        }
+    }
+}
+
+// Move position past the list that was just started, and past the comma
+// after it, the same way a number is read. Returns the whole list
+fn skip_list<'a>(packet: &'a str, position: &mut usize) -> &'a str {
+    let start = *position - 1;
+    let mut depth = 0;
+    for (i, c) in packet.char_indices().skip(start) {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            *position = i + 1;
+            if packet[i + 1..].starts_with(',') {
+                *position += 1;
+            }
+            return &packet[start..=i];
+        }
+    }
+    *position = packet.len();
+    return &packet[start..];
+}
+
+fn compare_pair(left: &str, right: &str) -> Option<bool> {
+    let mut left_position = 0;
+    let mut right_position = 0;
+    loop {
+        let left_value = get_packet_value(left, &mut left_position);
+        let right_value = get_packet_value(right, &mut right_position);
+
+        if left_value.is_none() && right_value.is_some() {
+            return Some(true);
+        }
+        if left_value.is_some() && right_value.is_none() {
+            return Some(false);
+        }
+        if left_value.is_none() && right_value.is_none() {
+            return None;
+        }
+        let left_value = left_value.unwrap();
+        let right_value = right_value.unwrap();
+
+        match (left_value, right_value) {
+            // Both sides are numbers
+            (PacketValue::Number(left), PacketValue::Number(right)) => {
+                if left < right {
+                    return Some(true);
+                }
+                if right < left {
+                    return Some(false);
+                }
+            }
+            // Left side is a number, while right is a list
+            (PacketValue::Number(val), PacketValue::StartList) => {
+                let left_str = format!("[{}]", val);
+                let right_str = skip_list(right, &mut right_position);
+                let comparison = compare_pair(&left_str, right_str);
+                if let Some(value) = comparison {
+                    return Some(value);
+                }
+            }
+            // Left side is a list, while right is a number
+            (PacketValue::StartList, PacketValue::Number(val)) => {
+                let left_str = skip_list(left, &mut left_position);
+                let right_str = format!("[{}]", val);
+                let comparison = compare_pair(left_str, &right_str);
+                if let Some(value) = comparison {
+                    return Some(value);
+                }
+            }
+            // Left list ends before right one
+            (PacketValue::EndList, value) if !matches!(value, PacketValue::EndList) => {
+                return Some(true);
+            }
+            // Right list ends before left one
+            (value, PacketValue::EndList) if !matches!(value, PacketValue::EndList) => {
+                return Some(false);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(PartialEq)]
+enum PacketToken {
+    Start,
+    Open,
+    Comma,
+    Number(usize),
+    Close,
+}
+
+// Check a packet is a well formed list, as the comparison relies on it
+fn check_packet(line: usize, text: &str) -> Result<(), ParseError> {
+    let mut previous = PacketToken::Start;
+    let mut depth = 0;
+
+    for (j, c) in text.char_indices() {
+        let error = |message| ParseError::at(line, text, &text[j..], message);
+
+        if let PacketToken::Number(start) = previous {
+            if !c.is_ascii_digit() && text[start..j].parse::<u32>().is_err() {
+                return Err(ParseError::at(
+                    line,
+                    text,
+                    &text[start..],
+                    "Expected a smaller number",
+                ));
+            }
+        }
+        previous = match (c, &previous) {
+            ('[', PacketToken::Start | PacketToken::Open | PacketToken::Comma) => {
+                depth += 1;
+                PacketToken::Open
+            }
+            (']', PacketToken::Open | PacketToken::Number(_) | PacketToken::Close) => {
+                depth -= 1;
+                PacketToken::Close
+            }
+            (',', PacketToken::Number(_) | PacketToken::Close) => PacketToken::Comma,
+            ('0'..='9', PacketToken::Open | PacketToken::Comma) => PacketToken::Number(j),
+            ('0'..='9', PacketToken::Number(start)) => PacketToken::Number(*start),
+            (_, PacketToken::Start) => return Err(error("Expected the packet to start with '['")),
+            (_, PacketToken::Open) => return Err(error("Expected a number, a list or ']'")),
+            (_, PacketToken::Comma) => return Err(error("Expected a number or a list")),
+            _ => return Err(error("Expected ',' or ']'")),
+        };
+        if depth == 0 && previous == PacketToken::Close && j + 1 < text.len() {
+            let rest = &text[j + 1..];
+            return Err(ParseError::at(
+                line,
+                text,
+                rest,
+                "Expected the end of the packet",
+            ));
+        }
+    }
+
+    if depth != 0 || previous == PacketToken::Start {
+        let end = &text[text.len()..];
+        return Err(ParseError::at(line, text, end, "Expected ']'"));
+    }
+    return Ok(());
+}
+
+/// Read every pair of packets, checking each one is a well formed list
+pub fn parse_input(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+    let mut pairs = Vec::new();
+    let mut packets: Vec<(usize, &str)> = Vec::new();
+
+    // A blank line, or the end of the input, closes each pair of packets
+    for (i, line) in input.lines().chain([""]).enumerate() {
+        if !line.is_empty() {
+            check_packet(i + 1, line)?;
+            packets.push((i + 1, line));
+            continue;
+        }
+        match packets.as_slice() {
+            [] => {}
+            [(_, left), (_, right)] => pairs.push((left.to_string(), right.to_string())),
+            [(line, text)] | [(line, text), ..] => {
+                return Err(ParseError::new(
+                    *line,
+                    1,
+                    text,
+                    "Expected a pair of packets, followed by a blank line",
+                ));
+            }
+        }
+        packets.clear();
+    }
+
+    return Ok(pairs);
+}
+
+/// Sum of the indices, starting at 1, of the pairs in the right order
+pub fn evaluate_packets(pairs: &[(String, String)]) -> u64 {
+    let mut indices: Vec<u64> = Vec::new();
+
+    for (i, (left, right)) in pairs.iter().enumerate() {
+        let comparison = compare_pair(left, right);
+        debug!(pair = i + 1, left, right, right_order = ?comparison, "compared");
+        if comparison.is_some_and(|v| v) {
+            indices.push(i as u64 + 1);
+        }
+    }
+
+    indices.iter().sum()
+}
+
+/// The positions of the two divider packets once every packet is sorted,
+/// multiplied together
+pub fn decode_key_packets(pairs: &[(String, String)]) -> Result<u64, SolveError> {
+    let mut packets: Vec<&str> = Vec::new();
+    for (left, right) in pairs {
+        packets.push(left);
+        packets.push(right);
+    }
+
+    const DIVIDER_1: &str = "[[2]]";
+    const DIVIDER_2: &str = "[[6]]";
+    packets.push(DIVIDER_1);
+    packets.push(DIVIDER_2);
+
+    packets.sort_by(|a, b| match compare_pair(a, b) {
+        Some(true) => Ordering::Less,
+        Some(false) => Ordering::Greater,
+        None => Ordering::Equal,
+    });
+
+    let mut first: u64 = 0;
+    let mut second: u64 = 0;
+    for (i, packet) in packets.iter().enumerate() {
+        if *packet == DIVIDER_1 {
+            first = i as u64 + 1;
+        } else if *packet == DIVIDER_2 {
+            second = i as u64 + 1;
+        }
+    }
+    debug!(first, second, "dividers");
+
+    return first
+        .checked_mul(second)
+        .ok_or_else(|| SolveError::new("Expected the decoder key to fit in 64 bits"));
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+    fn part_one(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(evaluate_packets(input).into());
+    }
+    fn part_two(input: &Self::Input) -> Result<Answer, SolveError> {
+        return Ok(decode_key_packets(input)?.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[derive(Debug, Clone)]
+    enum Tree {
+        Number(u32),
+        List(Vec<Tree>),
+    }
+
+    impl Tree {
+        fn render(&self) -> String {
+            return match self {
+                Tree::Number(value) => value.to_string(),
+                Tree::List(items) => {
+                    let items: Vec<String> = items.iter().map(|v| v.render()).collect();
+                    format!("[{}]", items.join(","))
+                }
+            };
+        }
+
+        // The ordering from the puzzle description, for reference
+        fn compare(&self, other: &Self) -> Ordering {
+            return match (self, other) {
+                (Tree::Number(left), Tree::Number(right)) => left.cmp(right),
+                (Tree::Number(value), list) => Tree::List(vec![Tree::Number(*value)]).compare(list),
+                (list, Tree::Number(value)) => {
+                    list.compare(&Tree::List(vec![Tree::Number(*value)]))
+                }
+                (Tree::List(left), Tree::List(right)) => left
+                    .iter()
+                    .zip(right)
+                    .map(|(l, r)| l.compare(r))
+                    .find(|v| v.is_ne())
+                    .unwrap_or(left.len().cmp(&right.len())),
+            };
+        }
+    }
+
+    fn tree() -> impl Strategy<Value = Tree> {
+        let value = (0u32..=10)
+            .prop_map(Tree::Number)
+            .prop_recursive(3, 16, 4, |inner| {
+                prop::collection::vec(inner, 0..4).prop_map(Tree::List)
+            });
+        return prop::collection::vec(value, 0..4).prop_map(Tree::List);
+    }
+
+    fn packet() -> impl Strategy<Value = String> {
+        return tree().prop_map(|v| v.render());
+    }
+
+    #[test]
+    fn part_one() {
+        let pairs = parse_input(EXAMPLE).unwrap();
+        let sum_of_indices = evaluate_packets(&pairs);
+        assert_eq!(sum_of_indices, 13);
+    }
+
+    #[test]
+    fn part_two() {
+        let pairs = parse_input(EXAMPLE).unwrap();
+        let decode_key = decode_key_packets(&pairs);
+        assert_eq!(decode_key, Ok(140));
+    }
+
+    #[test]
+    fn many_packets() {
+        // Both dividers end up past 65536, so their product takes more than
+        // 32 bits, as do the indices of the pairs in the right order
+        let pair = (String::from("[[1]]"), String::from("[[1],1]"));
+        let pairs = vec![pair; 40000];
+        assert_eq!(decode_key_packets(&pairs), Ok(80001 * 80002));
+        assert_eq!(evaluate_packets(&pairs), 40000 * 40001 / 2);
+    }
+
+    #[test]
+    fn number_against_list() {
+        assert_eq!(compare_pair("[5,1]", "[[5],0]"), Some(false));
+        assert_eq!(compare_pair("[[5],0]", "[5,1]"), Some(true));
+    }
+
+    #[test]
+    fn invalid_packets() {
+        let error = parse_input("[1,1,3]\n[1,1,5,1]\n\n[[1],[2,3,4]]\n[[1],4\n").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 7));
+
+        let error = parse_input("[1,,3]\n[1]\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+
+        let error = parse_input("[1]\n[2]\n[3]\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+
+        let error = parse_input("[1]\n[2]]\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+
+    proptest! {
+        #[test]
+        fn packet_equals_itself(a in packet()) {
+            prop_assert_eq!(compare_pair(&a, &a), None);
+        }
+
+        #[test]
+        fn ordering_is_antisymmetric(a in packet(), b in packet()) {
+            prop_assert_eq!(compare_pair(&a, &b), compare_pair(&b, &a).map(|v| !v));
+        }
+
+        #[test]
+        fn ordering_matches_reference(a in tree(), b in tree()) {
+            let expected = match a.compare(&b) {
+                Ordering::Less => Some(true),
+                Ordering::Equal => None,
+                Ordering::Greater => Some(false),
+            };
+            prop_assert_eq!(compare_pair(&a.render(), &b.render()), expected);
+        }
+
+        #[test]
+        fn ordering_is_transitive(a in packet(), b in packet(), c in packet()) {
+            if compare_pair(&a, &b) == Some(true) && compare_pair(&b, &c) == Some(true) {
+                prop_assert_eq!(compare_pair(&a, &c), Some(true));
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use common::solution_on_example;
+use day13::*;
+
+solution_on_example!(Day13, 13, 140);
+
+#[test]
+fn packets_from_outside() {
+    let pairs = day13::parse_input("[1,1,3,1,1]\n[1,1,5,1,1]\n\n[[1],[2,3,4]]\n[[1],4]\n").unwrap();
+
+    assert_eq!(evaluate_packets(&pairs), 3);
+}
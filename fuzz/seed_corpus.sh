@@ -0,0 +1,11 @@
+#!/bin/sh
+# Seed each fuzz target's corpus with the day's example and input
+cd "$(dirname "$0")" || exit 1
+
+for dir in ../day*/; do
+    day=$(basename "$dir")
+    mkdir -p "corpus/$day"
+    for file in "$dir"test*.txt "$dir"input.txt; do
+        [ -f "$file" ] && cp "$file" "corpus/$day/$(basename "$file")"
+    done
+done
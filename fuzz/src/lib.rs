@@ -0,0 +1,14 @@
+use common::Solution;
+
+// Feed arbitrary bytes to a day's parser, and solve both parts when they
+// parse. Bad input must come back as a ParseError or a SolveError, never as
+// a panic
+pub fn solve<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(parsed) = S::parse(input) {
+        let _ = S::part_one(&parsed);
+        let _ = S::part_two(&parsed);
+    }
+}
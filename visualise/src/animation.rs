@@ -0,0 +1,35 @@
+use ratatui::text::Line;
+
+// A solver's progress, split into frames computed up front so they can be
+// stepped through in any order
+pub trait Animation {
+    fn title(&self) -> String;
+    // Number of frames, the first one being before anything happens
+    fn len(&self) -> usize;
+    // The picture at a frame, at most height lines of width cells
+    fn draw(&self, frame: usize, width: usize, height: usize) -> Vec<Line<'static>>;
+    // What the solver knows so far, shown below the picture
+    fn status(&self, frame: usize) -> String;
+}
+
+// First row or column to show so that focus stays in the middle of a view
+// of the given length, without scrolling past the end of the grid
+pub fn get_offset(focus: usize, length: usize, view: usize) -> usize {
+    if length <= view {
+        return 0;
+    }
+    return focus.saturating_sub(view / 2).min(length - view);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_follows_focus() {
+        assert_eq!(get_offset(3, 10, 20), 0);
+        assert_eq!(get_offset(3, 100, 20), 0);
+        assert_eq!(get_offset(50, 100, 20), 40);
+        assert_eq!(get_offset(99, 100, 20), 80);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::animation::Animation;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout},
+    text::Line,
+    widgets::{Block, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+const MIN_SPEED: f64 = 1.0;
+const MAX_SPEED: f64 = 10000.0;
+
+pub struct App {
+    animation: Box<dyn Animation>,
+    pub frame: usize,
+    pub paused: bool,
+    // Frames per second
+    pub speed: f64,
+    // Time left over from the last tick, in frames
+    pending: f64,
+}
+
+impl App {
+    pub fn new(animation: Box<dyn Animation>, speed: f64) -> Self {
+        return App {
+            animation,
+            frame: 0,
+            paused: false,
+            speed: speed.clamp(MIN_SPEED, MAX_SPEED),
+            pending: 0.0,
+        };
+    }
+
+    fn last_frame(&self) -> usize {
+        return self.animation.len().saturating_sub(1);
+    }
+
+    // Move on as many frames as fit in the elapsed time, pausing at the end
+    pub fn tick(&mut self, elapsed: Duration) {
+        if self.paused {
+            return;
+        }
+        self.pending += elapsed.as_secs_f64() * self.speed;
+        let frames = self.pending.floor();
+        self.pending -= frames;
+        self.frame = (self.frame + frames as usize).min(self.last_frame());
+        if self.frame == self.last_frame() {
+            self.paused = true;
+        }
+    }
+
+    // Returns false once the user wants to quit
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => {
+                if self.frame == self.last_frame() {
+                    self.frame = 0;
+                }
+                self.paused = !self.paused;
+                self.pending = 0.0;
+            }
+            KeyCode::Right | KeyCode::Char('n') => {
+                self.paused = true;
+                self.frame = (self.frame + 1).min(self.last_frame());
+            }
+            KeyCode::Left | KeyCode::Char('p') => {
+                self.paused = true;
+                self.frame = self.frame.saturating_sub(1);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.speed = (self.speed * 2.0).min(MAX_SPEED);
+            }
+            KeyCode::Char('-') => self.speed = (self.speed / 2.0).max(MIN_SPEED),
+            KeyCode::Home | KeyCode::Char('r') => {
+                self.frame = 0;
+                self.pending = 0.0;
+            }
+            KeyCode::End => self.frame = self.last_frame(),
+            _ => {}
+        }
+        return true;
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let [picture_area, status_area, help_area] = Layout::vertical([
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let block = Block::bordered().title(self.animation.title());
+        let inner = block.inner(picture_area);
+        let picture = self
+            .animation
+            .draw(self.frame, inner.width.into(), inner.height.into());
+        frame.render_widget(Paragraph::new(picture).block(block), picture_area);
+
+        let status = format!(
+            " Frame {}/{} | {} | {} frames/s{}",
+            self.frame,
+            self.last_frame(),
+            self.animation.status(self.frame),
+            self.speed,
+            if self.paused { " | paused" } else { "" }
+        );
+        frame.render_widget(Line::from(status), status_area);
+        let help = " [space] play/pause  [←/→] step  [+/-] speed  [r] restart  [q] quit";
+        frame.render_widget(Line::from(help), help_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter;
+
+    impl Animation for Counter {
+        fn title(&self) -> String {
+            return String::from("Counter");
+        }
+        fn len(&self) -> usize {
+            return 10;
+        }
+        fn draw(&self, frame: usize, _: usize, _: usize) -> Vec<Line<'static>> {
+            return vec![Line::from(frame.to_string())];
+        }
+        fn status(&self, _: usize) -> String {
+            return String::new();
+        }
+    }
+
+    #[test]
+    fn plays_and_pauses_at_the_end() {
+        let mut app = App::new(Box::new(Counter), 4.0);
+        app.tick(Duration::from_millis(500));
+        assert_eq!(app.frame, 2);
+
+        app.tick(Duration::from_secs(10));
+        assert_eq!((app.frame, app.paused), (9, true));
+
+        // Playing again from the end starts over
+        app.handle_key(KeyCode::Char(' '));
+        assert_eq!((app.frame, app.paused), (0, false));
+    }
+
+    #[test]
+    fn steps_and_changes_speed() {
+        let mut app = App::new(Box::new(Counter), 4.0);
+        app.handle_key(KeyCode::Right);
+        app.handle_key(KeyCode::Right);
+        app.handle_key(KeyCode::Left);
+        assert_eq!((app.frame, app.paused), (1, true));
+
+        app.tick(Duration::from_secs(1));
+        assert_eq!(app.frame, 1);
+
+        app.handle_key(KeyCode::Char('+'));
+        assert_eq!(app.speed, 8.0);
+        app.handle_key(KeyCode::Char('-'));
+        app.handle_key(KeyCode::Char('-'));
+        assert_eq!(app.speed, 2.0);
+        assert!(!app.handle_key(KeyCode::Char('q')));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::animation::{get_offset, Animation};
+use day08::Grid;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+// Trees are checked one at a time, row by row, showing which can be seen
+// from outside the grid and where the best scenic score is so far
+pub struct Visibility {
+    grid: Grid,
+    width: usize,
+    visible: Vec<bool>,
+    scores: Vec<u64>,
+}
+
+impl Visibility {
+    pub fn new(grid: Grid) -> Self {
+        let width = grid.first().map_or(0, |v| v.len());
+        let mut visible = Vec::new();
+        let mut scores = Vec::new();
+        for y in 0..grid.len() {
+            for x in 0..grid[y].len() {
+                visible.push(day08::check_if_visible(&grid, x, y));
+                // Only shown and compared, so one too large to count is
+                // just the highest there is
+                let score = day08::get_scenic_score(&grid, x, y);
+                scores.push(score.unwrap_or(u64::MAX));
+            }
+        }
+        return Visibility {
+            grid,
+            width,
+            visible,
+            scores,
+        };
+    }
+
+    // Index of the best tree among the first checked ones
+    fn get_best(&self, checked: usize) -> Option<usize> {
+        return (0..checked).max_by_key(|i| (self.scores[*i], std::cmp::Reverse(*i)));
+    }
+}
+
+impl Animation for Visibility {
+    fn title(&self) -> String {
+        return String::from("Day 08: Treetop Tree House");
+    }
+
+    fn len(&self) -> usize {
+        return self.visible.len() + 1;
+    }
+
+    fn draw(&self, frame: usize, width: usize, height: usize) -> Vec<Line<'static>> {
+        let current = frame.checked_sub(1);
+        let best = self.get_best(frame);
+        let focus = current.unwrap_or(0);
+        let top = get_offset(focus / self.width.max(1), self.grid.len(), height);
+        let left = get_offset(focus % self.width.max(1), self.width, width);
+
+        let mut lines = Vec::new();
+        for (y, row) in self.grid.iter().enumerate().skip(top).take(height) {
+            let spans: Vec<Span> = row
+                .iter()
+                .enumerate()
+                .skip(left)
+                .take(width)
+                .map(|(x, tree)| {
+                    let i = y * self.width + x;
+                    let style = if Some(i) == current {
+                        Style::new().add_modifier(Modifier::REVERSED)
+                    } else if Some(i) == best {
+                        Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    } else if i >= frame {
+                        Style::new()
+                    } else if self.visible[i] {
+                        Style::new().fg(Color::Green)
+                    } else {
+                        Style::new().fg(Color::DarkGray)
+                    };
+                    Span::styled(tree.to_string(), style)
+                })
+                .collect();
+            lines.push(Line::from(spans));
+        }
+        return lines;
+    }
+
+    fn status(&self, frame: usize) -> String {
+        let visible = self.visible[..frame.min(self.visible.len())]
+            .iter()
+            .filter(|v| **v)
+            .count();
+        let score = self.get_best(frame).map_or(0, |i| self.scores[i]);
+        return format!("{} visible, best scenic score {}", visible, score);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_frames() {
+        let visibility = Visibility::new(day08::parse_input(day08::EXAMPLE).unwrap());
+
+        assert_eq!(visibility.len(), 26);
+        assert_eq!(visibility.status(0), "0 visible, best scenic score 0");
+        assert_eq!(visibility.status(25), "21 visible, best scenic score 8");
+        assert_eq!(visibility.draw(25, 80, 3).len(), 3);
+    }
+}
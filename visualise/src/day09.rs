@@ -0,0 +1,96 @@
+use crate::animation::Animation;
+use day09::{Knot, Motion};
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+use std::collections::HashSet;
+
+// The long rope moving one step at a time, following the head and leaving
+// a trail wherever its tail has been
+pub struct Rope {
+    steps: Vec<Vec<Knot>>,
+}
+
+impl Rope {
+    pub fn new(motions: &[Motion]) -> Self {
+        let start = vec![Knot::new(0, 0); 10];
+        let steps = [vec![start], day09::get_rope_steps(motions, 9)].concat();
+        return Rope { steps };
+    }
+
+    fn get_visited(&self, frame: usize) -> HashSet<(i32, i32)> {
+        return self.steps[..=frame]
+            .iter()
+            .filter_map(|v| v.last().map(|knot| knot.position()))
+            .collect();
+    }
+}
+
+impl Animation for Rope {
+    fn title(&self) -> String {
+        return String::from("Day 09: Rope Bridge");
+    }
+
+    fn len(&self) -> usize {
+        return self.steps.len();
+    }
+
+    fn draw(&self, frame: usize, width: usize, height: usize) -> Vec<Line<'static>> {
+        let rope = &self.steps[frame];
+        let visited = self.get_visited(frame);
+        let (head_x, head_y) = rope[0].position();
+        let left = head_x - width as i32 / 2;
+        let top = head_y + height as i32 / 2;
+
+        let mut lines = Vec::new();
+        for row in 0..height as i32 {
+            let y = top - row;
+            let spans: Vec<Span> = (0..width as i32)
+                .map(|column| {
+                    let x = left + column;
+                    let knot = rope.iter().position(|v| v.position() == (x, y));
+                    match knot {
+                        Some(0) => Span::styled("H", Style::new().fg(Color::Red)),
+                        Some(i) => Span::styled(i.to_string(), Style::new().fg(Color::Yellow)),
+                        None if visited.contains(&(x, y)) => {
+                            Span::styled("#", Style::new().fg(Color::Blue))
+                        }
+                        None if (x, y) == (0, 0) => Span::raw("s"),
+                        None => Span::styled(".", Style::new().fg(Color::DarkGray)),
+                    }
+                })
+                .collect();
+            lines.push(Line::from(spans));
+        }
+        return lines;
+    }
+
+    fn status(&self, frame: usize) -> String {
+        let (x, y) = self.steps[frame][0].position();
+        return format!(
+            "head at ({}, {}), tail visited {} positions",
+            x,
+            y,
+            self.get_visited(frame).len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_frames() {
+        let motions = day09::parse_input(include_str!("../../day09/test2.txt")).unwrap();
+        let rope = Rope::new(&motions);
+        let last = rope.len() - 1;
+
+        assert_eq!(rope.len(), 97);
+        assert!(rope.status(last).ends_with("tail visited 36 positions"));
+
+        let lines = rope.draw(0, 5, 3);
+        assert_eq!(lines[1].to_string(), "..H..");
+    }
+}
@@ -0,0 +1,96 @@
+use crate::animation::Animation;
+use day10::Instruction;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+const SCREEN_WIDTH: usize = 40;
+
+// The CRT beam drawing one pixel per cycle, lit when the sprite covers it
+pub struct Crt {
+    registers: Vec<i64>,
+}
+
+impl Crt {
+    pub fn new(instructions: &[Instruction]) -> Self {
+        return Crt {
+            registers: day10::get_register_values(instructions),
+        };
+    }
+
+    fn is_lit(&self, cycle: usize) -> bool {
+        let column = (cycle % SCREEN_WIDTH) as i64;
+        return (column - self.registers[cycle]).abs() <= 1;
+    }
+}
+
+impl Animation for Crt {
+    fn title(&self) -> String {
+        return String::from("Day 10: Cathode-Ray Tube");
+    }
+
+    fn len(&self) -> usize {
+        return self.registers.len() + 1;
+    }
+
+    fn draw(&self, frame: usize, _: usize, height: usize) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+
+        // Where the sprite is during the cycle being drawn
+        let register = self.registers.get(frame).copied();
+        let sprite: String = (0..SCREEN_WIDTH as i64)
+            .map(|v| match register {
+                Some(register) if (v - register).abs() <= 1 => '#',
+                _ => '.',
+            })
+            .collect();
+        lines.push(Line::styled(sprite, Style::new().fg(Color::Yellow)));
+        lines.push(Line::raw(""));
+
+        let rows = self.registers.len().div_ceil(SCREEN_WIDTH);
+        for row in 0..rows {
+            let spans: Vec<Span> = (row * SCREEN_WIDTH..(row + 1) * SCREEN_WIDTH)
+                .filter(|cycle| *cycle < self.registers.len())
+                .map(|cycle| match cycle {
+                    _ if cycle == frame => Span::styled(
+                        "#",
+                        Style::new().fg(Color::Red).add_modifier(Modifier::REVERSED),
+                    ),
+                    _ if cycle > frame => Span::styled(" ", Style::new()),
+                    _ if self.is_lit(cycle) => Span::styled("#", Style::new().fg(Color::Green)),
+                    _ => Span::styled(".", Style::new().fg(Color::DarkGray)),
+                })
+                .collect();
+            lines.push(Line::from(spans));
+        }
+        lines.truncate(height);
+        return lines;
+    }
+
+    fn status(&self, frame: usize) -> String {
+        let cycle = frame.min(self.registers.len().saturating_sub(1));
+        let register = self.registers.get(cycle).copied().unwrap_or(1);
+        return format!("cycle {}, X = {}", cycle + 1, register);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_frames() {
+        let crt = Crt::new(&day10::parse_input(day10::EXAMPLE).unwrap());
+
+        assert_eq!(crt.len(), 241);
+        assert_eq!(crt.status(19), "cycle 20, X = 21");
+
+        let lines = crt.draw(240, 40, 10);
+        let image: Vec<String> = lines[2..].iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            image.join("\n"),
+            include_str!("../../day10/test_image.txt").trim_end()
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::animation::{get_offset, Animation};
+use day12::Node;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use std::collections::HashMap;
+
+// Squares reached within this many frames are drawn as the frontier
+const FRONTIER: usize = 8;
+
+// Dijkstra's search spreading out from the start over the heightmap, then
+// the shortest path to the best signal being traced back
+pub struct Search {
+    width: usize,
+    height: usize,
+    elevations: Vec<Vec<char>>,
+    start: (usize, usize),
+    end: (usize, usize),
+    // When each square was reached, in frames
+    reached: HashMap<(usize, usize), usize>,
+    visits: Vec<(usize, usize)>,
+    path: Vec<(usize, usize)>,
+}
+
+impl Search {
+    pub fn new(height_map: &[Node], source: &Node, end: &Node) -> Self {
+        let width = height_map
+            .iter()
+            .map(|v| v.position().0 + 1)
+            .max()
+            .unwrap_or(0);
+        let height = height_map
+            .iter()
+            .map(|v| v.position().1 + 1)
+            .max()
+            .unwrap_or(0);
+        let mut elevations = vec![vec![' '; width]; height];
+        for node in height_map {
+            let (x, y) = node.position();
+            elevations[y][x] = node.elevation();
+        }
+
+        let mut visits = Vec::new();
+        let previous = day12::dijkstra_pathfind_visiting(height_map, source, false, |node| {
+            visits.push(node.position());
+        });
+
+        let mut path = Vec::new();
+        let mut node = previous.get(end);
+        if node.is_some() {
+            path.push(end.position());
+        }
+        while let Some(u) = node {
+            path.push(u.position());
+            node = previous.get(u);
+        }
+        path.reverse();
+
+        let reached = visits
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (*v, i + 1))
+            .collect();
+        return Search {
+            width,
+            height,
+            elevations,
+            start: source.position(),
+            end: end.position(),
+            reached,
+            visits,
+            path,
+        };
+    }
+
+    // How much of the path is traced at a frame, once the search is over
+    fn get_traced(&self, frame: usize) -> usize {
+        return frame.saturating_sub(self.visits.len());
+    }
+}
+
+impl Animation for Search {
+    fn title(&self) -> String {
+        return String::from("Day 12: Hill Climbing Algorithm");
+    }
+
+    fn len(&self) -> usize {
+        return self.visits.len() + self.path.len() + 1;
+    }
+
+    fn draw(&self, frame: usize, width: usize, height: usize) -> Vec<Line<'static>> {
+        let traced = &self.path[..self.get_traced(frame).min(self.path.len())];
+        let focus = match (traced.last(), frame.checked_sub(1)) {
+            (Some(position), _) => *position,
+            (None, Some(i)) => self.visits[i.min(self.visits.len() - 1)],
+            (None, None) => self.start,
+        };
+        let top = get_offset(focus.1, self.height, height);
+        let left = get_offset(focus.0, self.width, width);
+
+        let mut lines = Vec::new();
+        for (y, row) in self.elevations.iter().enumerate().skip(top).take(height) {
+            let spans: Vec<Span> = row
+                .iter()
+                .enumerate()
+                .skip(left)
+                .take(width)
+                .map(|(x, elevation)| {
+                    let reached = self.reached.get(&(x, y)).filter(|v| **v <= frame);
+                    let style = if (x, y) == self.start || (x, y) == self.end {
+                        Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD)
+                    } else if traced.contains(&(x, y)) {
+                        Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
+                    } else if reached.is_some_and(|v| frame - v < FRONTIER) {
+                        Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    } else if reached.is_some() {
+                        Style::new().fg(Color::Blue)
+                    } else {
+                        Style::new().fg(Color::DarkGray)
+                    };
+                    let symbol = match (x, y) {
+                        position if position == self.start => 'S',
+                        position if position == self.end => 'E',
+                        _ => *elevation,
+                    };
+                    Span::styled(symbol.to_string(), style)
+                })
+                .collect();
+            lines.push(Line::from(spans));
+        }
+        return lines;
+    }
+
+    fn status(&self, frame: usize) -> String {
+        let traced = self.get_traced(frame);
+        if traced == 0 {
+            return format!("reached {} of {} squares", frame, self.width * self.height);
+        }
+        let steps = self.path.len().saturating_sub(1);
+        return format!(
+            "tracing the path, {} of {} steps",
+            traced.min(self.path.len()) - 1,
+            steps
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_frames() {
+        let (height_map, source, end) = day12::parse_input(day12::EXAMPLE).unwrap();
+        let search = Search::new(&height_map, &source, &end);
+        let last = search.len() - 1;
+
+        assert_eq!(search.path.len(), 32);
+        assert_eq!(search.status(last), "tracing the path, 31 of 31 steps");
+        assert_eq!(search.draw(0, 8, 5)[0].to_string(), "Sabqponm");
+    }
+}
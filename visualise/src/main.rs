@@ -0,0 +1,129 @@
+use animation::Animation;
+use app::App;
+use common::input::{get_input_path, InputSource};
+use crossterm::event::{self, Event, KeyEventKind};
+use std::{
+    env, io, process,
+    time::{Duration, Instant},
+};
+
+mod animation;
+mod app;
+mod day08;
+mod day09;
+mod day10;
+mod day12;
+
+const USAGE: &str =
+    "Usage: visualise <8|9|10|12> [<input path> | - | --example] [--speed <frames/s>]";
+
+// How long to wait for a key before drawing the next frame
+const FRAME_TIME: Duration = Duration::from_millis(16);
+
+struct Args {
+    day: u8,
+    source: InputSource,
+    speed: f64,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter();
+
+    let day = match args.next().map(|v| v.parse::<u8>()) {
+        Some(Ok(day @ (8 | 9 | 10 | 12))) => day,
+        Some(_) => return Err(String::from("Expected day 8, 9, 10 or 12")),
+        None => return Err(String::from("Expected a day")),
+    };
+
+    let mut source = InputSource::File(get_input_path(day));
+    let mut speed = 30.0;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--speed" | "-s" => {
+                speed = match args.next().and_then(|v| v.parse().ok()) {
+                    Some(value) => value,
+                    None => return Err(String::from("Expected speed to be a number")),
+                };
+            }
+            "--example" | "-" => source = InputSource::from_arg(arg),
+            path if !path.starts_with('-') => source = InputSource::from_arg(path),
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+    return Ok(Args { day, source, speed });
+}
+
+fn get_animation(day: u8, input: &str) -> Result<Box<dyn Animation>, common::ParseError> {
+    return Ok(match day {
+        8 => Box::new(day08::Visibility::new(::day08::parse_input(input)?)),
+        9 => Box::new(day09::Rope::new(&::day09::parse_input(input)?)),
+        10 => Box::new(day10::Crt::new(&::day10::parse_input(input)?)),
+        _ => {
+            let (height_map, source, end) = ::day12::parse_input(input)?;
+            Box::new(day12::Search::new(&height_map, &source, &end))
+        }
+    });
+}
+
+fn get_example(day: u8) -> &'static str {
+    return match day {
+        8 => ::day08::EXAMPLE,
+        9 => ::day09::EXAMPLE,
+        10 => ::day10::EXAMPLE,
+        _ => ::day12::EXAMPLE,
+    };
+}
+
+fn run(mut app: App) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let mut last_tick = Instant::now();
+
+    loop {
+        terminal.draw(|frame| app.render(frame))?;
+
+        if event::poll(FRAME_TIME)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !app.handle_key(key.code) {
+                    break;
+                }
+            }
+        }
+        app.tick(last_tick.elapsed());
+        last_tick = Instant::now();
+    }
+
+    ratatui::restore();
+    return Ok(());
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let input = match args.source.read(get_example(args.day)) {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+    let animation = match get_animation(args.day, &input) {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("Invalid {} at {}", args.source, err);
+            process::exit(1);
+        }
+    };
+
+    if let Err(err) = run(App::new(animation, args.speed)) {
+        ratatui::restore();
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
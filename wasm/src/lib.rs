@@ -0,0 +1,93 @@
+use common::{Answer, Solution};
+use wasm_bindgen::prelude::*;
+
+// Parse the input, then solve the given part of it. Errors are ready to be
+// shown
+type Solver = fn(u8, &str) -> Result<Answer, String>;
+
+struct Day {
+    number: u8,
+    example: &'static str,
+    solve: Solver,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        return Day {
+            number: S::DAY,
+            example: S::EXAMPLE,
+            solve: solve_part::<S>,
+        };
+    }
+}
+
+fn solve_part<S: Solution>(part: u8, input: &str) -> Result<Answer, String> {
+    let parsed = S::parse(input).map_err(|err| format!("Invalid input at {}", err))?;
+    let answer = match part {
+        1 => S::part_one(&parsed),
+        _ => S::part_two(&parsed),
+    };
+    return answer.map_err(|err| format!("Could not solve input: {}", err));
+}
+
+const DAYS: [Day; 13] = [
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+];
+
+fn get_day(number: u8) -> Result<&'static Day, String> {
+    return DAYS
+        .iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| format!("Unknown day '{}'", number));
+}
+
+// Solve one part of a day. Grids come back a row per line, and errors are
+// thrown with a message that's ready to be shown
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    let day = get_day(day)?;
+    if part != 1 && part != 2 {
+        return Err(String::from("Expected part to be 1 or 2"));
+    }
+    return (day.solve)(part, input).map(|answer| answer.to_string());
+}
+
+// The example from a day's puzzle description
+#[wasm_bindgen]
+pub fn example(day: u8) -> Result<String, String> {
+    return get_day(day).map(|day| day.example.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_examples() {
+        assert_eq!(solve(1, 1, &example(1).unwrap()), Ok(String::from("24000")));
+        assert_eq!(solve(13, 2, &example(13).unwrap()), Ok(String::from("140")));
+        assert_eq!(solve(14, 1, ""), Err(String::from("Unknown day '14'")));
+        assert_eq!(
+            solve(1, 3, ""),
+            Err(String::from("Expected part to be 1 or 2"))
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let err = solve(4, 1, "2-4,6-8\n2-x,4-5\n").unwrap_err();
+        assert!(err.starts_with("Invalid input at line 2"), "{}", err);
+    }
+}
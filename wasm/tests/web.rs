@@ -0,0 +1,23 @@
+// Run in Node with `wasm-pack test --node wasm`
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn solve_every_example() {
+    for day in 1..=13 {
+        let input = wasm::example(day).unwrap();
+        for part in [1, 2] {
+            assert!(wasm::solve(day, part, &input).is_ok(), "day {}", day);
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn draw_crt_image() {
+    let image = wasm::solve(10, 2, &wasm::example(10).unwrap()).unwrap();
+    let rows: Vec<&str> = image.lines().collect();
+
+    assert_eq!(rows.len(), 6);
+    assert_eq!(rows[0], "##..##..##..##..##..##..##..##..##..##..");
+}